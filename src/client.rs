@@ -1,21 +1,97 @@
+use std::{
+    borrow::Cow,
+    collections::{HashMap, HashSet},
+    sync::{Arc, PoisonError, RwLock},
+    time::Duration,
+};
+
 use crate::{
     errors::Error,
     model::{
+        dedup::VideoDeduplicator,
         id::{ChannelId, VideoId},
-        Channel, ChannelFilter, ChannelVideoFilter, ChannelVideoType, CommentSearch, Language,
-        PaginatedResult, Video, VideoFilter, VideoFull, VideoSearch,
+        Channel, ChannelFilter, ChannelSortingCriteria, ChannelVideoFilter, ChannelVideoType,
+        CommentSearch, CommentSearchResult, ExtraVideoInfo, Language, LenientPage, LiveStatus,
+        Order, Organisation, PaginatedResult, Topic, Video, VideoFilter, VideoFull, VideoSearch,
+        VideoStatus, VideoType,
     },
-    util::validate_response,
+    util::{response_bytes, validate_json_bytes, validate_response_streaming},
 };
 
+use chrono::{DateTime, Utc};
+use serde::{de::DeserializeOwned, Serialize};
+
 #[cfg(feature = "streams")]
 use futures_core::Stream;
 
+#[cfg(feature = "single-flight")]
+use crate::single_flight::SingleFlight;
+
 #[derive(Debug, Clone)]
 /// The client used for interacting with the Holodex API.
+///
+/// The client is `Send + Sync`, and every method takes `&self`, so a single client
+/// can be shared between threads, for example in an [`Arc`], and used concurrently.
+/// Cloning it is cheap, and clones share the same connection pool and cached topics.
+///
+/// # Examples
+///
+/// Fetch several channels in parallel using one shared client.
+/// ```rust
+/// use std::{sync::Arc, thread};
+///
+/// # if std::env::var_os("HOLODEX_API_TOKEN").is_none() {
+/// #   std::env::set_var("HOLODEX_API_TOKEN", "my-api-token");
+/// # }
+/// let token = std::env::var("HOLODEX_API_TOKEN").unwrap();
+/// let client = Arc::new(holodex::Client::new(&token)?);
+///
+/// let handles: Vec<_> = ["UCoSrY_IQQVpmIRZ9Xf-y93g", "UCyl1z3jo3XHR1riLFKG5UAg"]
+///     .into_iter()
+///     .map(|id| {
+///         let client = Arc::clone(&client);
+///         thread::spawn(move || client.channel(&id.parse()?))
+///     })
+///     .collect();
+///
+/// for handle in handles {
+///     let channel = handle.join().expect("thread panicked")?;
+///     println!("{}", channel.name);
+/// }
+/// # Ok::<(), holodex::errors::Error>(())
+/// ```
 pub struct Client {
     http: ureq::Agent,
     token: String,
+    topics: Arc<RwLock<Option<HashSet<String>>>>,
+    timeout: Option<Duration>,
+    incomplete_retry: Option<IncompleteRetry>,
+    #[cfg(feature = "single-flight")]
+    in_flight: Arc<SingleFlight>,
+}
+
+/// Retries successful responses whose body is not complete yet, see [`Client::with_incomplete_retry`].
+#[derive(Clone)]
+struct IncompleteRetry {
+    attempts: u32,
+    delay: Duration,
+    is_incomplete: Arc<dyn Fn(&serde_json::Value) -> bool + Send + Sync>,
+}
+
+impl IncompleteRetry {
+    /// Bodies that are not valid JSON are never considered incomplete, so that they are reported as invalid.
+    fn is_incomplete(&self, body: &[u8]) -> bool {
+        serde_json::from_slice(body).is_ok_and(|body| (self.is_incomplete)(&body))
+    }
+}
+
+impl std::fmt::Debug for IncompleteRetry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("IncompleteRetry")
+            .field("attempts", &self.attempts)
+            .field("delay", &self.delay)
+            .finish_non_exhaustive()
+    }
 }
 
 impl Client {
@@ -23,6 +99,11 @@ impl Client {
     const USER_AGENT: &'static str =
         concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"),);
 
+    /// How many idle connections to the API a client created with [`new`][`Self::new`] keeps open.
+    pub const DEFAULT_MAX_IDLE_CONNECTIONS: usize = 1;
+    /// How many channels are requested per page when paging through `/channels`.
+    const CHANNEL_PAGE_SIZE: u32 = 50;
+
     #[must_use = "Unused Holodex client."]
     /// Create a new client with the provided API token.
     ///
@@ -40,16 +121,162 @@ impl Client {
     /// # Errors
     /// Will return [`Error::InvalidApiToken`] if `api_token` contains invalid characters.
     ///
-    /// Will return [`Error::HttpClientCreationError`] if a TLS backend cannot be initialized, or the resolver cannot load the system configuration.
+    /// Will return [`Error::HttpClientCreationError`] if the TLS backend selected by the
+    /// `rustls` or `native-tls` feature cannot be initialized, or the resolver cannot load the system configuration.
+    /// If both features are enabled, `native-tls` is used.
     pub fn new(api_token: &str) -> Result<Self, Error> {
-        let http = ureq::builder().user_agent(Self::USER_AGENT).build();
+        Self::with_max_idle_connections(api_token, Self::DEFAULT_MAX_IDLE_CONNECTIONS)
+    }
+
+    #[must_use = "Unused Holodex client."]
+    /// Create a new client with the provided API token, keeping up to `max_idle_connections`
+    /// connections to the API open between requests.
+    ///
+    /// Connections are kept alive and reused for later requests, so polling the API does not
+    /// open a new connection for every request. The client created by [`new`][`Self::new`] keeps
+    /// [`DEFAULT_MAX_IDLE_CONNECTIONS`][`Self::DEFAULT_MAX_IDLE_CONNECTIONS`] connection open,
+    /// which is enough for sequential requests. When sending many requests concurrently from
+    /// several threads, allowing more idle connections lets each thread reuse one.
+    ///
+    /// Idle connections are closed when the API closes them, there is no separate idle timeout.
+    /// A value of zero disables connection reuse.
+    /// This only limits connections to the API host. The pool of `ureq`, shared by all hosts,
+    /// keeps its default size of 100 idle connections, which also caps this value.
+    ///
+    /// # Examples
+    /// Create a client for use from 8 threads at a time:
+    /// ```rust
+    /// # if std::env::var_os("HOLODEX_API_TOKEN").is_none() {
+    /// #   std::env::set_var("HOLODEX_API_TOKEN", "my-api-token");
+    /// # }
+    /// let token = std::env::var("HOLODEX_API_TOKEN").unwrap();
+    /// let client = holodex::Client::with_max_idle_connections(&token, 8)?;
+    /// # Ok::<(), holodex::errors::Error>(())
+    /// ```
+    ///
+    /// # Errors
+    /// Will return [`Error::InvalidApiToken`] if `api_token` contains invalid characters.
+    ///
+    /// Will return [`Error::HttpClientCreationError`] if the TLS backend selected by the
+    /// `rustls` or `native-tls` feature cannot be initialized, or the resolver cannot load the system configuration.
+    /// If both features are enabled, `native-tls` is used.
+    pub fn with_max_idle_connections(
+        api_token: &str,
+        max_idle_connections: usize,
+    ) -> Result<Self, Error> {
+        // Only the per-host limit is set, since every request goes to the API,
+        // and `ureq`'s global pool limit is left at its default.
+        let builder = ureq::builder()
+            .user_agent(Self::USER_AGENT)
+            .max_idle_connections_per_host(max_idle_connections);
+
+        // Installing a connector overrides the `rustls` default, so `native-tls` wins if both are enabled.
+        #[cfg(feature = "native-tls")]
+        let builder = builder
+            .tls_connector(Arc::new(native_tls::TlsConnector::new().map_err(|e| {
+                Error::HttpClientCreationError(std::io::Error::other(e).into())
+            })?));
+
+        let http = builder.build();
 
         Ok(Self {
             http,
             token: api_token.to_owned(),
+            topics: Arc::default(),
+            timeout: None,
+            incomplete_retry: None,
+            #[cfg(feature = "single-flight")]
+            in_flight: Arc::default(),
         })
     }
 
+    #[must_use]
+    /// Returns a copy of the client where every request has the given timeout.
+    ///
+    /// The timeout covers the whole request, from connecting until the response has been read.
+    /// By default requests have no overall timeout, only a connect timeout of 30 seconds,
+    /// and setting a timeout here replaces both.
+    /// Requests that time out fail with [`Error::Timeout`].
+    ///
+    /// The copy shares its connection pool and caches with the original client,
+    /// so it is cheap to create one per call that needs a different timeout.
+    ///
+    /// # Examples
+    ///
+    /// Poll live streams with a tight timeout, while allowing a slower batch fetch.
+    /// ```rust
+    /// use std::time::Duration;
+    /// use holodex::model::{builders::VideoFilterBuilder, id::ChannelId};
+    ///
+    /// # if std::env::var_os("HOLODEX_API_TOKEN").is_none() {
+    /// #   std::env::set_var("HOLODEX_API_TOKEN", "my-api-token");
+    /// # }
+    /// let token = std::env::var("HOLODEX_API_TOKEN").unwrap();
+    /// let client = holodex::Client::new(&token)?;
+    ///
+    /// let channels: Vec<ChannelId> = vec!["UCS9uQI-jC3DE0L4IpXyvr6w".parse()?];
+    /// let live = client
+    ///     .with_timeout(Duration::from_secs(2))
+    ///     .live_from_channels(&channels)?;
+    ///
+    /// let filter = VideoFilterBuilder::new().limit(50).build();
+    /// let videos = client.with_timeout(Duration::from_secs(60)).videos(&filter)?;
+    /// # Ok::<(), holodex::errors::Error>(())
+    /// ```
+    pub fn with_timeout(&self, timeout: Duration) -> Self {
+        Self {
+            timeout: Some(timeout),
+            ..self.clone()
+        }
+    }
+
+    #[must_use]
+    /// Returns a copy of the client that retries responses which succeeded, but whose body is incomplete.
+    ///
+    /// Some data is not indexed immediately, for example a newly created channel can briefly be returned
+    /// as an empty placeholder. `is_incomplete` is called with the JSON body of every successful response,
+    /// and if it returns `true`, the request is sent again after waiting for `delay`,
+    /// up to `attempts` more times. The last response is used even if it is still incomplete.
+    ///
+    /// This is off by default. Failed requests are never retried, only incomplete responses are.
+    /// Like [`with_timeout`][`Self::with_timeout`], the copy shares its connection pool and caches with the original client.
+    ///
+    /// # Examples
+    ///
+    /// Retry channels that are returned without a name.
+    /// ```rust
+    /// use std::time::Duration;
+    ///
+    /// # if std::env::var_os("HOLODEX_API_TOKEN").is_none() {
+    /// #   std::env::set_var("HOLODEX_API_TOKEN", "my-api-token");
+    /// # }
+    /// let token = std::env::var("HOLODEX_API_TOKEN").unwrap();
+    /// let client = holodex::Client::new(&token)?.with_incomplete_retry(
+    ///     3,
+    ///     Duration::from_millis(500),
+    ///     |body| body.get("name").is_none_or(serde_json::Value::is_null),
+    /// );
+    ///
+    /// let channel = client.channel(&"UCNVEsYbiZjH5QLmGeSgTSzg".parse()?)?;
+    /// println!("{}", channel.name);
+    /// # Ok::<(), holodex::errors::Error>(())
+    /// ```
+    pub fn with_incomplete_retry(
+        &self,
+        attempts: u32,
+        delay: Duration,
+        is_incomplete: impl Fn(&serde_json::Value) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            incomplete_retry: Some(IncompleteRetry {
+                attempts,
+                delay,
+                is_incomplete: Arc::new(is_incomplete),
+            }),
+            ..self.clone()
+        }
+    }
+
     /// Query videos.
     ///
     /// Pretty much everything you need.
@@ -98,20 +325,20 @@ impl Client {
     ///
     /// Will return [`Error::InvalidResponse`] if the API returned a faulty response or server error.
     pub fn videos(&self, parameters: &VideoFilter) -> Result<PaginatedResult<Video>, Error> {
-        Self::query_videos(&self.http, &self.token, "/videos", parameters)
+        self.query_videos("/videos", parameters)
     }
 
-    #[cfg(feature = "streams")]
-    /// Returns a stream of all videos matching the `filter`.
+    #[cfg(feature = "debug-raw")]
+    /// Query videos, returning the raw JSON body of the response along with the parsed videos.
+    ///
+    /// This sends the same request as [`videos`][`Self::videos`], and is meant for inspecting
+    /// responses that parse unexpectedly, for example to attach the exact body to a bug report.
+    /// The body is parsed twice, once into a [`serde_json::Value`] and once into the videos.
     ///
     /// # Examples
     ///
-    /// Get all streams that are currently live.
     /// ```rust
-    /// # fn main() -> Result<(), holodex::errors::Error> {
-    /// # tokio_test::block_on(async {
-    /// use holodex::model::{builders::VideoFilterBuilder, VideoStatus, VideoType};
-    /// use futures::{self, pin_mut, StreamExt, TryStreamExt};
+    /// use holodex::model::VideoFilter;
     ///
     /// # if std::env::var_os("HOLODEX_API_TOKEN").is_none() {
     /// #   std::env::set_var("HOLODEX_API_TOKEN", "my-api-token");
@@ -119,63 +346,57 @@ impl Client {
     /// let token = std::env::var("HOLODEX_API_TOKEN").unwrap();
     /// let client = holodex::Client::new(&token)?;
     ///
-    /// let filter = VideoFilterBuilder::new()
-    ///     .video_type(VideoType::Stream)
-    ///     .status(&[VideoStatus::Live])
-    ///     .build();
-    ///
-    /// let stream = client.video_stream(&filter);
-    /// pin_mut!(stream);
+    /// let (videos, raw) = client.videos_with_raw(&VideoFilter::default())?;
     ///
-    /// while let Some(video) = stream.try_next().await? {
-    ///     println!("{}", video.title);
-    /// }
-    /// # Ok(())
-    /// # })
-    /// # }
+    /// println!("Parsed {} videos from:\n{:#}", videos.len(), raw);
+    /// # Ok::<(), holodex::errors::Error>(())
     /// ```
-    pub fn video_stream<'a>(
-        &'a self,
-        parameters: &'a VideoFilter,
-    ) -> impl Stream<Item = Result<Video, Error>> + 'a {
-        Self::stream_endpoint(&self.http, &self.token, "/videos", parameters)
+    ///
+    /// # Errors
+    /// Will return [`Error::ApiRequestFailed`] if sending the API request fails.
+    ///
+    /// Will return [`Error::InvalidResponse`] if the API returned a faulty response or server error.
+    pub fn videos_with_raw(
+        &self,
+        parameters: &VideoFilter,
+    ) -> Result<(PaginatedResult<Video>, serde_json::Value), Error> {
+        let request = self
+            .http
+            .get(&format!("{}/videos", Self::ENDPOINT))
+            .set("x-apikey", &self.token);
+
+        let raw: serde_json::Value =
+            self.send(Self::with_query(request, parameters)?, "/videos", None)?;
+        let videos = serde_json::from_value(raw.clone()).map_err(|e| Error::InvalidResponse {
+            endpoint: "/videos".into(),
+            source: crate::errors::ParseError::ValueParseError(e).into(),
+        })?;
+
+        Ok((videos, raw))
     }
 
-    /// Query live and upcoming videos.
+    /// Query the videos tagged with the given topic, such as `singing` or `minecraft`.
     ///
-    /// This is somewhat similar to calling [`videos`][`Self::videos`].
-    ///
-    /// However, this endpoint imposes these default values on the query parameters:
-    /// You can choose to override them by providing your own values.
+    /// This is a shorthand for calling [`videos`][`Self::videos`] with the `filter`,
+    /// where the [`topic`][`VideoFilter::topic`] is set to `topic`,
+    /// and the [`video_type`][`VideoFilter::video_type`] is always [`Stream`][`VideoType::Stream`],
+    /// since clips are never tagged with topics and filtering clips by topic would return nothing.
     ///
-    /// | Parameter  | Default |
-    /// |------------|---------|
-    /// | Status     | [[`Live`][`crate::model::VideoStatus::Live`], [`Upcoming`][`crate::model::VideoStatus::Upcoming`]] |
-    /// | Video type | [`Stream`][`crate::model::VideoType::Stream`]            |
-    /// | Sort by    | [`AvailableAt`][`crate::model::VideoSortingCriteria::AvailableAt`]     |
-    /// | Order      | [`Ascending`][`crate::model::Order::Ascending`]     |
-    /// | Max upcoming hours | 48 |
-    /// | Limit      | 9999    |
-    /// | Include    | [[`LiveInfo`][`crate::model::ExtraVideoInfo::LiveInfo`]] |
+    /// Use [`topics`][`Self::topics`] to list the topics that exist.
     ///
     /// # Examples
     ///
-    /// Find live or upcoming streams from Hololive talents:
+    /// Print the latest Minecraft streams from Hololive.
     /// ```rust
-    /// use holodex::model::{Organisation, VideoFilter};
+    /// use holodex::model::VideoFilter;
     ///
     /// # if std::env::var_os("HOLODEX_API_TOKEN").is_none() {
     /// #   std::env::set_var("HOLODEX_API_TOKEN", "my-api-token");
     /// # }
     /// let token = std::env::var("HOLODEX_API_TOKEN").unwrap();
     /// let client = holodex::Client::new(&token)?;
-    /// let parameters = VideoFilter {
-    ///     org: Some(Organisation::Hololive),
-    ///     ..Default::default()
-    /// };
-    /// let currently_live = client.live(&parameters)?;
     ///
-    /// for video in currently_live.items() {
+    /// for video in client.videos_by_topic("minecraft", &VideoFilter::default())? {
     ///     println!("{}", video.title);
     /// }
     /// # Ok::<(), holodex::errors::Error>(())
@@ -185,21 +406,28 @@ impl Client {
     /// Will return [`Error::ApiRequestFailed`] if sending the API request fails.
     ///
     /// Will return [`Error::InvalidResponse`] if the API returned a faulty response or server error.
-    pub fn live(&self, parameters: &VideoFilter) -> Result<PaginatedResult<Video>, Error> {
-        Self::query_videos(&self.http, &self.token, "/live", parameters)
+    pub fn videos_by_topic(
+        &self,
+        topic: &str,
+        filter: &VideoFilter,
+    ) -> Result<PaginatedResult<Video>, Error> {
+        self.videos(&VideoFilter {
+            topic: Some(topic.to_owned()),
+            video_type: VideoType::Stream,
+            ..filter.clone()
+        })
     }
 
-    /// Query videos related to channel.
+    /// Query videos, parsing each video on its own so that a malformed video does not fail the whole page.
     ///
-    /// A simplified endpoint for access channel specific data.
-    /// If you want more customization, the same result can be obtained by
-    /// calling [`videos`][`Self::videos`].
+    /// This sends the same request as [`videos`][`Self::videos`], but returns a [`LenientPage`],
+    /// with the videos that were parsed successfully in [`items`][`LenientPage::items`],
+    /// and the position of each video that could not be parsed along with why in [`errors`][`LenientPage::errors`].
     ///
     /// # Examples
     ///
-    /// Find some English clips of Pekora:
     /// ```rust
-    /// use holodex::model::{Language, ChannelVideoType, ChannelVideoFilter};
+    /// use holodex::model::VideoFilter;
     ///
     /// # if std::env::var_os("HOLODEX_API_TOKEN").is_none() {
     /// #   std::env::set_var("HOLODEX_API_TOKEN", "my-api-token");
@@ -207,15 +435,13 @@ impl Client {
     /// let token = std::env::var("HOLODEX_API_TOKEN").unwrap();
     /// let client = holodex::Client::new(&token)?;
     ///
-    /// let parameters = ChannelVideoFilter {
-    ///     languages: vec![Language::English],
-    ///     ..Default::default()
-    /// };
-    /// let pekora_ch_id = "UC1DCedRgGHBdm81E1llLhOQ".parse()?;
-    /// let english_clips = client.videos_from_channel(&pekora_ch_id, ChannelVideoType::Clips, &parameters)?;
+    /// let page = client.videos_lenient(&VideoFilter::default())?;
     ///
-    /// for clip in english_clips.items() {
-    ///     println!("{}", clip.title);
+    /// for (index, error) in &page.errors {
+    ///     eprintln!("Skipped video {}: {}", index, error);
+    /// }
+    /// for video in &page.items {
+    ///     println!("{}", video.title);
     /// }
     /// # Ok::<(), holodex::errors::Error>(())
     /// ```
@@ -223,67 +449,43 @@ impl Client {
     /// # Errors
     /// Will return [`Error::ApiRequestFailed`] if sending the API request fails.
     ///
-    /// Will return [`Error::InvalidResponse`] if the API returned a faulty response or server error.
-    pub fn videos_from_channel(
-        &self,
-        channel_id: &ChannelId,
-        video_type: ChannelVideoType,
-        parameters: &ChannelVideoFilter,
-    ) -> Result<PaginatedResult<Video>, Error> {
-        let query_string = serde_urlencoded::to_string(parameters)
-            .map_err(|e| Error::FilterCreationError(e.to_string()))?;
-        let query_pairs: Vec<(&str, String)> = serde_urlencoded::from_str(&query_string)
-            .map_err(|e| Error::FilterCreationError(e.to_string()))?;
-
-        let mut request = self
-            .http
-            .get(&format!(
-                "{}/channels/{}/{}",
-                Self::ENDPOINT,
-                channel_id,
-                video_type
-            ))
-            .set("x-apikey", &self.token);
-
-        for (key, value) in query_pairs {
-            request = request.query(key, &value);
-        }
-        let res = request.call().map_err(|e| Error::ApiRequestFailed {
-            endpoint: "/channels/{channel_id}/{type}",
-            source: e,
-        })?;
-
-        let videos = validate_response(res).map_err(|e| Error::InvalidResponse {
-            endpoint: "/channels/{channel_id}/{type}",
-            source: e,
-        })?;
+    /// Will return [`Error::InvalidResponse`] if the API returned a server error,
+    /// or a response that is not a list of videos at all.
+    pub fn videos_lenient(&self, parameters: &VideoFilter) -> Result<LenientPage<Video>, Error> {
+        let page: PaginatedResult<serde_json::Value> = self.query_videos("/videos", parameters)?;
 
-        Ok(videos)
+        Ok(page.into_iter().collect())
     }
 
-    /// Quickly access live/upcoming for a set of channels.
+    /// Query videos, parsing each video as it is read instead of buffering the whole response first.
     ///
-    /// This method is similar to [`live`](#method.live) and usually replies much faster.
-    /// It is more friendly in general. The cost to execute a lookup is significantly cheaper.
-    /// It's unfortunately less customizable as a result.
+    /// This sends the same request as [`videos`][`Self::videos`], but only one video is held in memory at a time,
+    /// which keeps memory use low for very large pages, such as when archiving with a high [`limit`][`VideoFilter::limit`].
+    /// The response is also not limited in size, unlike with the buffered methods.
     ///
-    /// We recommend using this if you have a fixed set of channel IDs to look up status for.
+    /// The request is always sent on its own, so it is never shared with identical requests in flight,
+    /// and never retried by [`with_incomplete_retry`][`Self::with_incomplete_retry`].
+    ///
+    /// Once the iterator returns an error, it ends, since the rest of the response can no longer be read.
     ///
     /// # Examples
     ///
-    /// Find if Amelia and/or Gura are live:
     /// ```rust
+    /// use holodex::model::{builders::VideoFilterBuilder, Organisation};
+    ///
     /// # if std::env::var_os("HOLODEX_API_TOKEN").is_none() {
     /// #   std::env::set_var("HOLODEX_API_TOKEN", "my-api-token");
     /// # }
     /// let token = std::env::var("HOLODEX_API_TOKEN").unwrap();
     /// let client = holodex::Client::new(&token)?;
     ///
-    /// let channels = vec!["UCoSrY_IQQVpmIRZ9Xf-y93g".parse()?, "UCyl1z3jo3XHR1riLFKG5UAg".parse()?];
-    /// let streams = client.live_from_channels(&channels)?;
+    /// let filter = VideoFilterBuilder::new()
+    ///     .organisation(Organisation::Hololive)
+    ///     .limit(9999)
+    ///     .build();
     ///
-    /// if !streams.is_empty() {
-    ///     println!("At least one of the channels is live!");
+    /// for video in client.videos_streaming(&filter)? {
+    ///     println!("{}", video?.title);
     /// }
     /// # Ok::<(), holodex::errors::Error>(())
     /// ```
@@ -291,164 +493,276 @@ impl Client {
     /// # Errors
     /// Will return [`Error::ApiRequestFailed`] if sending the API request fails.
     ///
-    /// Will return [`Error::InvalidResponse`] if the API returned a faulty response or server error.
-    pub fn live_from_channels(
+    /// Will return [`Error::InvalidResponse`] if the API returned a server error.
+    /// The iterator yields [`Error::InvalidResponse`] if a video, or the response around it, could not be parsed.
+    pub fn videos_streaming(
         &self,
-        channel_ids: &[ChannelId],
-    ) -> Result<PaginatedResult<Video>, Error> {
-        let res = self
+        parameters: &VideoFilter,
+    ) -> Result<impl Iterator<Item = Result<Video, Error>> + Send, Error> {
+        const ENDPOINT: &str = "/videos";
+
+        let request = self
             .http
-            .get(&format!("{}/users/live", Self::ENDPOINT))
-            .set("x-apikey", &self.token)
-            .query(
-                "channels",
-                &channel_ids
-                    .iter()
-                    .map(|c| &*c.0)
-                    .collect::<Vec<&str>>()
-                    .join(","),
-            )
-            .call()
-            .map_err(|e| Error::ApiRequestFailed {
-                endpoint: "/users/live",
-                source: e,
-            })?;
-
-        let videos = validate_response(res).map_err(|e| Error::InvalidResponse {
-            endpoint: "/users/live",
-            source: e,
-        })?;
+            .get(&format!("{}{}", Self::ENDPOINT, ENDPOINT))
+            .set("x-apikey", &self.token);
+        let response = self.respond(
+            Self::with_query(request, parameters)?,
+            &Cow::Borrowed(ENDPOINT),
+            None,
+        )?;
+
+        let videos = validate_response_streaming::<Video>(response)
+            .map_err(|e| Error::invalid_response(ENDPOINT, e))?;
 
-        Ok(videos)
+        Ok(videos.map(|video| video.map_err(|e| Error::invalid_response(ENDPOINT, e.into()))))
     }
 
-    /// Get channel information.
+    #[cfg(feature = "streams")]
+    /// Returns a stream of all videos matching the `filter`.
     ///
     /// # Examples
     ///
-    /// Find out how many subscribers Astel has.
+    /// Get all streams that are currently live.
     /// ```rust
+    /// # fn main() -> Result<(), holodex::errors::Error> {
+    /// # tokio_test::block_on(async {
+    /// use holodex::model::{builders::VideoFilterBuilder, VideoStatus, VideoType};
+    /// use futures::{self, pin_mut, StreamExt, TryStreamExt};
+    ///
     /// # if std::env::var_os("HOLODEX_API_TOKEN").is_none() {
     /// #   std::env::set_var("HOLODEX_API_TOKEN", "my-api-token");
     /// # }
     /// let token = std::env::var("HOLODEX_API_TOKEN").unwrap();
     /// let client = holodex::Client::new(&token)?;
     ///
-    /// let channel_id = "UCNVEsYbiZjH5QLmGeSgTSzg".parse()?;
-    /// let channel = client.channel(&channel_id)?;
+    /// let filter = VideoFilterBuilder::new()
+    ///     .video_type(VideoType::Stream)
+    ///     .status(&[VideoStatus::Live])
+    ///     .build();
     ///
-    /// if let Some(subs) = &channel.stats.subscriber_count {
-    ///     println!("Astel has {} subscribers", subs);
+    /// let stream = client.video_stream(&filter);
+    /// pin_mut!(stream);
+    ///
+    /// while let Some(video) = stream.try_next().await? {
+    ///     println!("{}", video.title);
     /// }
-    /// # Ok::<(), holodex::errors::Error>(())
+    /// # Ok(())
+    /// # })
+    /// # }
     /// ```
-    ///
-    /// # Errors
-    /// Will return [`Error::ApiRequestFailed`] if sending the API request fails.
-    ///
-    /// Will return [`Error::InvalidResponse`] if the API returned a faulty response or server error.
-    pub fn channel(&self, channel_id: &ChannelId) -> Result<Channel, Error> {
-        let res = self
-            .http
-            .get(&format!("{}/channels/{}", Self::ENDPOINT, channel_id))
-            .set("x-apikey", &self.token)
-            .call()
-            .map_err(|e| Error::ApiRequestFailed {
-                endpoint: "/channels/{channel_id}",
-                source: e,
-            })?;
-
-        let channel = validate_response(res).map_err(|e| Error::InvalidResponse {
-            endpoint: "/channels/{channel_id}",
-            source: e,
-        })?;
-
-        Ok(channel)
+    pub fn video_stream<'a>(
+        &'a self,
+        parameters: &'a VideoFilter,
+    ) -> impl Stream<Item = Result<Video, Error>> + 'a {
+        self.stream_endpoint("/videos", parameters)
     }
 
-    /// Get all channels matching the given filter.
+    #[cfg(feature = "streams")]
+    /// Returns a stream of pages of videos matching the `filter`.
+    ///
+    /// This is the same as [`video_stream`][`Self::video_stream`],
+    /// except that each page of videos is yielded as it arrives,
+    /// which lets you process the videos in batches.
     ///
     /// # Examples
     ///
-    /// Print the top 10 vtuber channels by number of subscribers.
+    /// Count the videos in each page of past streams.
     /// ```rust
-    /// use holodex::model::{
-    ///     builders::ChannelFilterBuilder, ChannelFilter, ChannelSortingCriteria,
-    ///     Order, Organisation
-    /// };
-    ///
-    /// # if std::env::var_os("HOLODEX_API_TOKEN").is_none() {
+    /// # fn main() -> Result<(), holodex::errors::Error> {
+    /// # tokio_test::block_on(async {
+    /// use holodex::model::{builders::VideoFilterBuilder, VideoStatus, VideoType};
+    /// use futures::{self, pin_mut, StreamExt, TryStreamExt};
+    ///
+    /// # if std::env::var_os("HOLODEX_API_TOKEN").is_none() {
     /// #   std::env::set_var("HOLODEX_API_TOKEN", "my-api-token");
     /// # }
     /// let token = std::env::var("HOLODEX_API_TOKEN").unwrap();
     /// let client = holodex::Client::new(&token)?;
     ///
-    /// let filter = ChannelFilterBuilder::new()
-    ///     .sort_by(ChannelSortingCriteria::SubscriberCount)
-    ///     .order(Order::Descending)
-    ///     .limit(10)
-    ///     .build()?;
+    /// let filter = VideoFilterBuilder::new()
+    ///     .video_type(VideoType::Stream)
+    ///     .status(&[VideoStatus::Past])
+    ///     .build();
     ///
-    /// let channels = client.channels(&filter)?;
+    /// let stream = client.video_page_stream(&filter).take(5);
+    /// pin_mut!(stream);
     ///
-    /// for channel in channels {
-    ///     println!(
-    ///         "{} has {} subscribers!",
-    ///         channel.name, channel.stats.subscriber_count.unwrap_or_default()
-    ///     );
+    /// while let Some(page) = stream.try_next().await? {
+    ///     println!("Received {} videos", page.len());
     /// }
-    /// # Ok::<(), holodex::errors::Error>(())
+    /// # Ok(())
+    /// # })
+    /// # }
     /// ```
+    pub fn video_page_stream<'a>(
+        &'a self,
+        parameters: &'a VideoFilter,
+    ) -> impl Stream<Item = Result<PaginatedResult<Video>, Error>> + 'a {
+        self.stream_endpoint_pages("/videos", parameters)
+    }
+
+    /// Returns an iterator over all videos matching the `filter`, fetching one page at a time.
     ///
-    /// # Errors
-    /// Will return [`Error::ApiRequestFailed`] if sending the API request fails.
+    /// This is a blocking alternative to [`video_stream`][`Self::video_stream`].
+    /// The `paginated`, `limit` and `offset` fields of the filter are ignored.
     ///
-    /// Will return [`Error::InvalidResponse`] if the API returned a faulty response or server error.
-    pub fn channels(&self, filter: &ChannelFilter) -> Result<Vec<Channel>, Error> {
-        let query_string = serde_urlencoded::to_string(filter)
-            .map_err(|e| Error::FilterCreationError(e.to_string()))?;
-        let query_pairs: Vec<(&str, String)> = serde_urlencoded::from_str(&query_string)
-            .map_err(|e| Error::FilterCreationError(e.to_string()))?;
-
-        let mut request = self
-            .http
-            .get(&format!("{}/channels", Self::ENDPOINT))
-            .set("x-apikey", &self.token);
+    /// When stitching together several overlapping queries, combine it with
+    /// [`dedup_by_id`][`crate::model::dedup::DedupByIdExt::dedup_by_id`]
+    /// to skip videos that have already been returned.
+    ///
+    /// # Examples
+    ///
+    /// List clips of two talents, where clips featuring both should only be listed once.
+    /// ```rust
+    /// use holodex::model::{builders::VideoFilterBuilder, dedup::DedupByIdExt, VideoType};
+    ///
+    /// # if std::env::var_os("HOLODEX_API_TOKEN").is_none() {
+    /// #   std::env::set_var("HOLODEX_API_TOKEN", "my-api-token");
+    /// # }
+    /// let token = std::env::var("HOLODEX_API_TOKEN").unwrap();
+    /// let client = holodex::Client::new(&token)?;
+    ///
+    /// let filters = ["UCoSrY_IQQVpmIRZ9Xf-y93g", "UCyl1z3jo3XHR1riLFKG5UAg"]
+    ///     .into_iter()
+    ///     .map(|id| {
+    ///         Ok(VideoFilterBuilder::new()
+    ///             .mentioned_channel_id(id.parse()?)
+    ///             .video_type(VideoType::Clip)
+    ///             .build())
+    ///     })
+    ///     .collect::<Result<Vec<_>, holodex::errors::Error>>()?;
+    ///
+    /// let videos = filters
+    ///     .iter()
+    ///     .flat_map(|filter| client.videos_iter(filter))
+    ///     .dedup_by_id();
+    ///
+    /// for video in videos {
+    ///     println!("{}", video?.title);
+    /// }
+    /// # Ok::<(), holodex::errors::Error>(())
+    /// ```
+    pub fn videos_iter(
+        &self,
+        filter: &VideoFilter,
+    ) -> impl Iterator<Item = Result<Video, Error>> + '_ {
+        let mut pages = VideoPages::new("/videos", filter);
+        let mut page = Vec::new().into_iter();
 
-        for (key, value) in query_pairs {
-            request = request.query(key, &value);
-        }
+        std::iter::from_fn(move || loop {
+            if let Some(video) = page.next() {
+                return Some(Ok(video));
+            }
 
-        let res = request.call().map_err(|e| Error::ApiRequestFailed {
-            endpoint: "/channels",
-            source: e,
-        })?;
+            match pages.next_page(self)? {
+                Ok(videos) => page = videos.into_iter(),
+                Err(error) => return Some(Err(error)),
+            }
+        })
+    }
 
-        let channels = validate_response(res).map_err(|e| Error::InvalidResponse {
-            endpoint: "/channels",
-            source: e,
-        })?;
+    /// Returns an iterator over all videos matching the `filter` that became available at or after `since`,
+    /// skipping those already in `seen`.
+    ///
+    /// This pages through the results like [`videos_iter`][`Self::videos_iter`],
+    /// with [`from`][`VideoFilter::from`] set to `since`.
+    /// Timestamps only have a resolution of one second, so videos available in the same second as `since`
+    /// are included even if they were already returned by an earlier call.
+    /// To skip those, every returned video is added to `seen`, and videos already in it are left out,
+    /// so passing the same deduplicator to every call returns each video once.
+    ///
+    /// # Examples
+    ///
+    /// Check for new videos every few minutes, continuing from the newest video seen so far.
+    /// ```rust,no_run
+    /// use chrono::{TimeZone, Utc};
+    /// use holodex::model::{dedup::VideoDeduplicator, VideoFilter, VideoStatus};
+    ///
+    /// # if std::env::var_os("HOLODEX_API_TOKEN").is_none() {
+    /// #   std::env::set_var("HOLODEX_API_TOKEN", "my-api-token");
+    /// # }
+    /// let token = std::env::var("HOLODEX_API_TOKEN").unwrap();
+    /// let client = holodex::Client::new(&token)?;
+    ///
+    /// let filter = VideoFilter {
+    ///     status: VideoStatus::active_statuses().to_vec(),
+    ///     ..Default::default()
+    /// };
+    /// let mut newest = Utc.with_ymd_and_hms(2021, 9, 1, 0, 0, 0).unwrap();
+    /// let mut seen = VideoDeduplicator::new();
+    ///
+    /// loop {
+    ///     let videos = client
+    ///         .videos_since(newest, &filter, &mut seen)
+    ///         .collect::<Result<Vec<_>, _>>()?;
+    ///
+    ///     for video in videos {
+    ///         println!("New video: {}", video.title);
+    ///         newest = newest.max(video.available_at);
+    ///     }
+    ///
+    ///     std::thread::sleep(std::time::Duration::from_secs(300));
+    /// }
+    /// # Ok::<(), holodex::errors::Error>(())
+    /// ```
+    pub fn videos_since<'a>(
+        &'a self,
+        since: DateTime<Utc>,
+        filter: &VideoFilter,
+        seen: &'a mut VideoDeduplicator,
+    ) -> impl Iterator<Item = Result<Video, Error>> + 'a {
+        let filter = VideoFilter {
+            from: Some(since),
+            ..filter.clone()
+        };
 
-        Ok(channels)
+        self.videos_iter(&filter).filter(move |video| {
+            video
+                .as_ref()
+                .map_or(true, |v| v.available_at >= since && seen.insert(&v.id))
+        })
     }
 
-    /// Get a single video's metadata.
+    /// Query live and upcoming videos.
+    ///
+    /// This is somewhat similar to calling [`videos`][`Self::videos`].
+    ///
+    /// However, this endpoint imposes these default values on the query parameters:
+    /// You can choose to override them by providing your own values.
+    ///
+    /// | Parameter  | Default |
+    /// |------------|---------|
+    /// | Status     | [[`Live`][`crate::model::VideoStatus::Live`], [`Upcoming`][`crate::model::VideoStatus::Upcoming`]] |
+    /// | Video type | [`Stream`][`crate::model::VideoType::Stream`]            |
+    /// | Sort by    | [`AvailableAt`][`crate::model::VideoSortingCriteria::AvailableAt`]     |
+    /// | Order      | [`Ascending`][`crate::model::Order::Ascending`]     |
+    /// | Max upcoming hours | 48 |
+    /// | Limit      | 9999    |
+    /// | Include    | [[`LiveInfo`][`crate::model::ExtraVideoInfo::LiveInfo`]] |
+    ///
+    /// The API may occasionally return the same video more than once, for example
+    /// while it is transitioning from upcoming to live. Use
+    /// [`PaginatedResult::dedup_by_id`] if each video should only appear once.
     ///
     /// # Examples
     ///
-    /// Find songs from Coco's graduation stream :(
+    /// Find live or upcoming streams from Hololive talents:
     /// ```rust
+    /// use holodex::model::{Organisation, VideoFilter};
+    ///
     /// # if std::env::var_os("HOLODEX_API_TOKEN").is_none() {
     /// #   std::env::set_var("HOLODEX_API_TOKEN", "my-api-token");
     /// # }
     /// let token = std::env::var("HOLODEX_API_TOKEN").unwrap();
     /// let client = holodex::Client::new(&token)?;
+    /// let parameters = VideoFilter {
+    ///     org: Some(Organisation::Hololive),
+    ///     ..Default::default()
+    /// };
+    /// let currently_live = client.live(&parameters)?;
     ///
-    /// let coco_graduation = "IhiievWaZMI".parse()?;
-    /// let metadata = client.video(&coco_graduation)?;
-    ///
-    /// for song in &metadata.songs {
-    ///     println!("{}", song);
+    /// for video in currently_live.items() {
+    ///     println!("{}", video.title);
     /// }
     /// # Ok::<(), holodex::errors::Error>(())
     /// ```
@@ -457,27 +771,38 @@ impl Client {
     /// Will return [`Error::ApiRequestFailed`] if sending the API request fails.
     ///
     /// Will return [`Error::InvalidResponse`] if the API returned a faulty response or server error.
-    pub fn video(&self, video_id: &VideoId) -> Result<VideoFull, Error> {
-        self.get_video::<()>(video_id, None)
+    pub fn live(&self, parameters: &VideoFilter) -> Result<PaginatedResult<Video>, Error> {
+        self.query_videos("/live", parameters)
     }
 
-    /// Get a single video's metadata, along with any indexed comments containing timestamps.
+    /// Get the streams from the given organisation that are live right now.
+    ///
+    /// This is a shorthand for calling [`live`][`Self::live`] with this filter,
+    /// where every other parameter uses the [`VideoFilter`] default:
+    ///
+    /// | Parameter    | Value   |
+    /// |--------------|---------|
+    /// | Organisation | `org`   |
+    /// | Status       | [[`Live`][`crate::model::VideoStatus::Live`]] |
+    /// | Video type   | [`Stream`][`crate::model::VideoType::Stream`] |
+    /// | Limit        | 100     |
+    ///
+    /// Videos that the API returns more than once are only included once.
     ///
     /// # Examples
     ///
-    /// Find all timestamps for Ollie's birthday stream (in 2021).
+    /// Print all Hololive streams that are live right now.
     /// ```rust
+    /// use holodex::model::Organisation;
+    ///
     /// # if std::env::var_os("HOLODEX_API_TOKEN").is_none() {
     /// #   std::env::set_var("HOLODEX_API_TOKEN", "my-api-token");
     /// # }
     /// let token = std::env::var("HOLODEX_API_TOKEN").unwrap();
     /// let client = holodex::Client::new(&token)?;
     ///
-    /// let ollie_birthday = "v6o7LBrQs-I".parse()?;
-    /// let metadata = client.video_with_timestamps(&ollie_birthday)?;
-    ///
-    /// for comment in &metadata.comments {
-    ///     println!("{}", comment);
+    /// for video in client.live_now(Organisation::Hololive)? {
+    ///     println!("{}", video.title);
     /// }
     /// # Ok::<(), holodex::errors::Error>(())
     /// ```
@@ -486,17 +811,33 @@ impl Client {
     /// Will return [`Error::ApiRequestFailed`] if sending the API request fails.
     ///
     /// Will return [`Error::InvalidResponse`] if the API returned a faulty response or server error.
-    pub fn video_with_timestamps(&self, video_id: &VideoId) -> Result<VideoFull, Error> {
-        self.get_video(video_id, Some(&[("c", "1")]))
+    pub fn live_now(&self, org: Organisation) -> Result<Vec<Video>, Error> {
+        let mut result = self.live(&VideoFilter {
+            org: Some(org),
+            status: vec![VideoStatus::Live],
+            video_type: VideoType::Stream,
+            limit: 100,
+            ..VideoFilter::default()
+        })?;
+        result.dedup_by_id();
+
+        Ok(result.into_items())
     }
 
-    /// Get a single video's metadata, along with any recommended videos in languages matching the given filter.
+    /// Query videos related to channel.
+    ///
+    /// A simplified endpoint for access channel specific data.
+    /// If you want more customization, the same result can be obtained by
+    /// calling [`videos`][`Self::videos`].
+    ///
+    /// The result is a [`PaginatedResult::Page`] with a `total` if [`paginated`][`ChannelVideoFilter::paginated`] is set,
+    /// and otherwise bare [`PaginatedResult::Items`]. Use [`PaginatedResult::has_more`] to tell if there are more pages.
     ///
     /// # Examples
     ///
-    /// Get English videos related to Korone's birthday stream (2021).
+    /// Find some English clips of Pekora:
     /// ```rust
-    /// use holodex::model::Language;
+    /// use holodex::model::{Language, ChannelVideoType, ChannelVideoFilter};
     ///
     /// # if std::env::var_os("HOLODEX_API_TOKEN").is_none() {
     /// #   std::env::set_var("HOLODEX_API_TOKEN", "my-api-token");
@@ -504,11 +845,15 @@ impl Client {
     /// let token = std::env::var("HOLODEX_API_TOKEN").unwrap();
     /// let client = holodex::Client::new(&token)?;
     ///
-    /// let korone_birthday = "2l3i7MulCgs-I".parse()?;
-    /// let metadata = client.video_with_related(&korone_birthday, &[Language::English])?;
+    /// let parameters = ChannelVideoFilter {
+    ///     languages: vec![Language::English],
+    ///     ..Default::default()
+    /// };
+    /// let pekora_ch_id = "UC1DCedRgGHBdm81E1llLhOQ".parse()?;
+    /// let english_clips = client.videos_from_channel(&pekora_ch_id, ChannelVideoType::Clips, &parameters)?;
     ///
-    /// for related in &metadata.related {
-    ///     println!("{}", related.title);
+    /// for clip in english_clips.items() {
+    ///     println!("{}", clip.title);
     /// }
     /// # Ok::<(), holodex::errors::Error>(())
     /// ```
@@ -517,52 +862,52 @@ impl Client {
     /// Will return [`Error::ApiRequestFailed`] if sending the API request fails.
     ///
     /// Will return [`Error::InvalidResponse`] if the API returned a faulty response or server error.
-    pub fn video_with_related(
+    pub fn videos_from_channel(
         &self,
-        video_id: &VideoId,
-        related_language_filter: &[Language],
-    ) -> Result<VideoFull, Error> {
-        self.get_video(
-            video_id,
-            Some(&[(
-                "lang",
-                related_language_filter
-                    .iter()
-                    .map(ToString::to_string)
-                    .collect::<Vec<String>>()
-                    .join(","),
-            )]),
+        channel_id: &ChannelId,
+        video_type: ChannelVideoType,
+        parameters: &ChannelVideoFilter,
+    ) -> Result<PaginatedResult<Video>, Error> {
+        let request = self
+            .http
+            .get(&format!(
+                "{}/channels/{}/{}",
+                Self::ENDPOINT,
+                channel_id,
+                video_type
+            ))
+            .set("x-apikey", &self.token);
+
+        self.send(
+            Self::with_query(request, parameters)?,
+            "/channels/{channel_id}/{type}",
+            None,
         )
     }
 
-    /// Search for videos matching the given search conditions.
+    /// Quickly access live/upcoming for a set of channels.
     ///
-    /// Searching for `topics` and `clips` together is not supported,
-    /// because clips do not contain `topics`.
+    /// This method is similar to [`live`](#method.live) and usually replies much faster.
+    /// It is more friendly in general. The cost to execute a lookup is significantly cheaper.
+    /// It's unfortunately less customizable as a result.
+    ///
+    /// We recommend using this if you have a fixed set of channel IDs to look up status for.
     ///
     /// # Examples
     ///
-    /// Find the five latest Okayu/Korone collab streams.
+    /// Find if Amelia and/or Gura are live:
     /// ```rust
-    /// use holodex::model::{builders::VideoSearchBuilder, SearchOrder, VideoType};
-    ///
     /// # if std::env::var_os("HOLODEX_API_TOKEN").is_none() {
     /// #   std::env::set_var("HOLODEX_API_TOKEN", "my-api-token");
     /// # }
     /// let token = std::env::var("HOLODEX_API_TOKEN").unwrap();
     /// let client = holodex::Client::new(&token)?;
     ///
-    /// let search = VideoSearchBuilder::new()
-    ///     .order(SearchOrder::Newest)
-    ///     .channels(&["UCvaTdHTWBGv3MKj3KVqJVCw".parse()?, "UChAnqc_AY5_I3Px5dig3X1Q".parse()?])
-    ///     .types(&[VideoType::Stream])
-    ///     .limit(5)
-    ///     .build();
-    ///
-    /// let results = client.search_videos(&search)?;
+    /// let channels = vec!["UCoSrY_IQQVpmIRZ9Xf-y93g".parse()?, "UCyl1z3jo3XHR1riLFKG5UAg".parse()?];
+    /// let streams = client.live_from_channels(&channels)?;
     ///
-    /// for result in results {
-    ///     println!("{}", result.title);
+    /// if !streams.is_empty() {
+    ///     println!("At least one of the channels is live!");
     /// }
     /// # Ok::<(), holodex::errors::Error>(())
     /// ```
@@ -571,38 +916,37 @@ impl Client {
     /// Will return [`Error::ApiRequestFailed`] if sending the API request fails.
     ///
     /// Will return [`Error::InvalidResponse`] if the API returned a faulty response or server error.
-    pub fn search_videos(
+    pub fn live_from_channels(
         &self,
-        search_parameters: &VideoSearch,
+        channel_ids: &[ChannelId],
     ) -> Result<PaginatedResult<Video>, Error> {
-        let res = self
+        let request = self
             .http
-            .post(&format!("{}/search/videoSearch", Self::ENDPOINT))
+            .get(&format!("{}/users/live", Self::ENDPOINT))
             .set("x-apikey", &self.token)
-            .send_json(
-                ureq::serde_json::to_value(search_parameters)
-                    .map_err(|e| Error::FilterCreationError(e.to_string()))?,
-            )
-            .map_err(|e| Error::ApiRequestFailed {
-                endpoint: "/search/videoSearch",
-                source: e,
-            })?;
-
-        let videos = validate_response(res).map_err(|e| Error::InvalidResponse {
-            endpoint: "/search/videoSearch",
-            source: e,
-        })?;
+            .query(
+                "channels",
+                &channel_ids
+                    .iter()
+                    .map(|c| &*c.0)
+                    .collect::<Vec<&str>>()
+                    .join(","),
+            );
 
-        Ok(videos)
+        self.send(request, "/users/live", None)
     }
 
-    /// Search for comments matching the given search conditions.
+    /// Quickly check which of the given channels are live or have upcoming streams.
+    ///
+    /// This uses the same endpoint as [`live_from_channels`][`Self::live_from_channels`],
+    /// but only keeps the video ID, channel ID, status and start time of each video,
+    /// which is cheaper to parse when polling many channels frequently.
     ///
     /// # Examples
     ///
-    /// Find the 50 oldest comments containing the word `peko` on streams from Nijisanji.
+    /// Find if Amelia and/or Gura are live:
     /// ```rust
-    /// use holodex::model::{builders::CommentSearchBuilder, Organisation, SearchOrder, VideoType};
+    /// use holodex::model::VideoStatus;
     ///
     /// # if std::env::var_os("HOLODEX_API_TOKEN").is_none() {
     /// #   std::env::set_var("HOLODEX_API_TOKEN", "my-api-token");
@@ -610,17 +954,12 @@ impl Client {
     /// let token = std::env::var("HOLODEX_API_TOKEN").unwrap();
     /// let client = holodex::Client::new(&token)?;
     ///
-    /// let search = CommentSearchBuilder::new("peko")
-    ///     .order(SearchOrder::Oldest)
-    ///     .organisations(&[Organisation::Nijisanji])
-    ///     .types(&[VideoType::Stream])
-    ///     .limit(50)
-    ///     .build();
-    ///
-    /// let videos_with_comments = client.search_comments(&search)?;
+    /// let channels = vec!["UCoSrY_IQQVpmIRZ9Xf-y93g".parse()?, "UCyl1z3jo3XHR1riLFKG5UAg".parse()?];
     ///
-    /// for comment in videos_with_comments.into_iter().flat_map(|v| v.comments) {
-    ///     println!("{}", comment);
+    /// for status in client.live_status(&channels)? {
+    ///     if status.status == VideoStatus::Live {
+    ///         println!("{} is live in {}", status.channel_id, status.id);
+    ///     }
     /// }
     /// # Ok::<(), holodex::errors::Error>(())
     /// ```
@@ -629,128 +968,1458 @@ impl Client {
     /// Will return [`Error::ApiRequestFailed`] if sending the API request fails.
     ///
     /// Will return [`Error::InvalidResponse`] if the API returned a faulty response or server error.
-    pub fn search_comments(
-        &self,
-        search_parameters: &CommentSearch,
-    ) -> Result<PaginatedResult<VideoFull>, Error> {
-        let res = self
+    pub fn live_status(&self, channel_ids: &[ChannelId]) -> Result<Vec<LiveStatus>, Error> {
+        let request = self
             .http
-            .post(&format!("{}/search/commentSearch", Self::ENDPOINT))
+            .get(&format!("{}/users/live", Self::ENDPOINT))
             .set("x-apikey", &self.token)
-            .send_json(
-                ureq::serde_json::to_value(search_parameters)
-                    .map_err(|e| Error::FilterCreationError(e.to_string()))?,
-            )
-            .map_err(|e| Error::ApiRequestFailed {
-                endpoint: "/search/commentSearch",
-                source: e,
-            })?;
-
-        let videos_with_comments = validate_response(res).map_err(|e| Error::InvalidResponse {
-            endpoint: "/search/commentSearch",
-            source: e,
-        })?;
+            .query(
+                "channels",
+                &channel_ids
+                    .iter()
+                    .map(|c| &*c.0)
+                    .collect::<Vec<&str>>()
+                    .join(","),
+            );
 
-        Ok(videos_with_comments)
+        self.send(request, "/users/live", None)
+            .map(PaginatedResult::<LiveStatus>::into_items)
     }
 
-    fn get_video<T>(&self, video_id: &VideoId, query: Option<&T>) -> Result<VideoFull, Error>
-    where
-        T: serde::Serialize + Sync + Send + ?Sized + std::fmt::Debug,
-    {
-        let query_string = serde_urlencoded::to_string(query)
+    /// Get channel information.
+    ///
+    /// Channels can only be looked up by their ID starting with `UC`.
+    /// The API has no way of resolving `YouTube` handles such as `@AmeliaWatson` or custom URLs,
+    /// and parsing those as a [`ChannelId`] fails with [`Error::UnresolvableChannelHandle`].
+    ///
+    /// # Examples
+    ///
+    /// Find out how many subscribers Astel has.
+    /// ```rust
+    /// # if std::env::var_os("HOLODEX_API_TOKEN").is_none() {
+    /// #   std::env::set_var("HOLODEX_API_TOKEN", "my-api-token");
+    /// # }
+    /// let token = std::env::var("HOLODEX_API_TOKEN").unwrap();
+    /// let client = holodex::Client::new(&token)?;
+    ///
+    /// let channel_id = "UCNVEsYbiZjH5QLmGeSgTSzg".parse()?;
+    /// let channel = client.channel(&channel_id)?;
+    ///
+    /// if let Some(subs) = &channel.stats.subscriber_count {
+    ///     println!("Astel has {} subscribers", subs);
+    /// }
+    /// # Ok::<(), holodex::errors::Error>(())
+    /// ```
+    ///
+    /// # Errors
+    /// Will return [`Error::ApiRequestFailed`] if sending the API request fails.
+    ///
+    /// Will return [`Error::InvalidResponse`] if the API returned a faulty response or server error.
+    ///
+    /// Will return [`Error::NotFound`] if no channel with the given ID exists.
+    pub fn channel(&self, channel_id: &ChannelId) -> Result<Channel, Error> {
+        let request = self
+            .http
+            .get(&format!("{}/channels/{}", Self::ENDPOINT, channel_id))
+            .set("x-apikey", &self.token);
+
+        self.send(request, "/channels/{channel_id}", None)
+            .map_err(|e| e.or_not_found("channel", channel_id))
+    }
+
+    /// Get channel information for every channel that uploaded any of the given videos.
+    ///
+    /// The channel IDs are deduplicated before fetching, so each channel is only requested once.
+    /// Since the API does not offer a way to look up several channels by ID at once,
+    /// this sends one request per distinct channel.
+    ///
+    /// # Examples
+    ///
+    /// Print the subscriber count of each channel that is currently live.
+    /// ```rust
+    /// use holodex::model::{VideoFilter, VideoStatus};
+    ///
+    /// # if std::env::var_os("HOLODEX_API_TOKEN").is_none() {
+    /// #   std::env::set_var("HOLODEX_API_TOKEN", "my-api-token");
+    /// # }
+    /// let token = std::env::var("HOLODEX_API_TOKEN").unwrap();
+    /// let client = holodex::Client::new(&token)?;
+    ///
+    /// let live = client.live(&VideoFilter {
+    ///     status: vec![VideoStatus::Live],
+    ///     ..VideoFilter::default()
+    /// })?;
+    /// let channels = client.resolve_channels(&live)?;
+    ///
+    /// for video in live {
+    ///     if let Some(channel) = channels.get(video.channel.id()) {
+    ///         println!("{} has {:?} subscribers", channel.name, channel.stats.subscriber_count);
+    ///     }
+    /// }
+    /// # Ok::<(), holodex::errors::Error>(())
+    /// ```
+    ///
+    /// # Errors
+    /// Will return [`Error::ApiRequestFailed`] if sending any of the API requests fails.
+    ///
+    /// Will return [`Error::InvalidResponse`] if the API returned a faulty response or server error.
+    pub fn resolve_channels(&self, videos: &[Video]) -> Result<HashMap<ChannelId, Channel>, Error> {
+        let channel_ids: HashSet<&ChannelId> =
+            videos.iter().map(|video| video.channel.id()).collect();
+
+        channel_ids
+            .into_iter()
+            .map(|id| Ok((id.clone(), self.channel(id)?)))
+            .collect()
+    }
+
+    /// Get all channels matching the given filter.
+    ///
+    /// If the filter [excludes inactive channels][`ChannelFilter::exclude_inactive`],
+    /// those are removed after the page is received, so fewer than `limit` channels may be returned.
+    ///
+    /// # Examples
+    ///
+    /// Print the top 10 vtuber channels by number of subscribers.
+    /// ```rust
+    /// use holodex::model::{
+    ///     builders::ChannelFilterBuilder, ChannelFilter, ChannelSortingCriteria,
+    ///     Order, Organisation
+    /// };
+    ///
+    /// # if std::env::var_os("HOLODEX_API_TOKEN").is_none() {
+    /// #   std::env::set_var("HOLODEX_API_TOKEN", "my-api-token");
+    /// # }
+    /// let token = std::env::var("HOLODEX_API_TOKEN").unwrap();
+    /// let client = holodex::Client::new(&token)?;
+    ///
+    /// let filter = ChannelFilterBuilder::new()
+    ///     .sort_by(ChannelSortingCriteria::SubscriberCount)
+    ///     .order(Order::Descending)
+    ///     .limit(10)
+    ///     .build()?;
+    ///
+    /// let channels = client.channels(&filter)?;
+    ///
+    /// for channel in channels {
+    ///     println!(
+    ///         "{} has {} subscribers!",
+    ///         channel.name, channel.stats.subscriber_count.unwrap_or_default()
+    ///     );
+    /// }
+    /// # Ok::<(), holodex::errors::Error>(())
+    /// ```
+    ///
+    /// # Errors
+    /// Will return [`Error::ApiRequestFailed`] if sending the API request fails.
+    ///
+    /// Will return [`Error::InvalidResponse`] if the API returned a faulty response or server error.
+    pub fn channels(&self, filter: &ChannelFilter) -> Result<Vec<Channel>, Error> {
+        let mut channels = self.fetch_channels(filter)?;
+        Self::retain_channels(&mut channels, filter);
+
+        Ok(channels)
+    }
+
+    /// Get a page of channels, without applying the client-side parts of the filter.
+    fn fetch_channels(&self, filter: &ChannelFilter) -> Result<Vec<Channel>, Error> {
+        let request = self
+            .http
+            .get(&format!("{}/channels", Self::ENDPOINT))
+            .set("x-apikey", &self.token);
+
+        self.send(Self::with_query(request, filter)?, "/channels", None)
+    }
+
+    /// Apply the parts of the filter the API does not support, keeping the order of the channels.
+    fn retain_channels(channels: &mut Vec<Channel>, filter: &ChannelFilter) {
+        if filter.exclude_inactive {
+            channels.retain(|channel| !channel.inactive);
+        }
+    }
+
+    /// Returns an iterator over all channels matching the given filter.
+    ///
+    /// Channels are requested in pages of 50, starting from the filter's `offset`,
+    /// the filter's `limit` is ignored.
+    /// Since the `/channels` endpoint does not report how many channels matched the filter,
+    /// iteration stops once a page with fewer than 50 channels is returned.
+    /// Channels left out by [`exclude_inactive`][`ChannelFilter::exclude_inactive`]
+    /// do not count towards this, so iteration does not stop early because of them.
+    ///
+    /// If a request fails, the error is yielded and iteration stops.
+    ///
+    /// # Examples
+    ///
+    /// Print the names of all channels from Nijisanji.
+    /// ```rust
+    /// use holodex::model::{builders::ChannelFilterBuilder, Organisation};
+    ///
+    /// # if std::env::var_os("HOLODEX_API_TOKEN").is_none() {
+    /// #   std::env::set_var("HOLODEX_API_TOKEN", "my-api-token");
+    /// # }
+    /// let token = std::env::var("HOLODEX_API_TOKEN").unwrap();
+    /// let client = holodex::Client::new(&token)?;
+    ///
+    /// let filter = ChannelFilterBuilder::new()
+    ///     .organisation(Organisation::Nijisanji)
+    ///     .build()?;
+    ///
+    /// for channel in client.channels_iter(&filter) {
+    ///     println!("{}", channel?.name);
+    /// }
+    /// # Ok::<(), holodex::errors::Error>(())
+    /// ```
+    pub fn channels_iter(
+        &self,
+        filter: &ChannelFilter,
+    ) -> impl Iterator<Item = Result<Channel, Error>> + '_ {
+        let mut pages = ChannelPages::new(filter);
+        let mut page = Vec::new().into_iter();
+
+        std::iter::from_fn(move || loop {
+            if let Some(channel) = page.next() {
+                return Some(Ok(channel));
+            }
+
+            match pages.next_page(self)? {
+                Ok(channels) => page = channels.into_iter(),
+                Err(error) => return Some(Err(error)),
+            }
+        })
+    }
+
+    /// Get up to `total` channels matching the given filter, requesting as many pages as needed.
+    ///
+    /// The `/channels` endpoint returns at most 50 channels per request, so this pages through the channels
+    /// with [`channels_iter`][`Self::channels_iter`], starting from the filter's `offset`.
+    /// The filter's `limit` is ignored, and no more pages are requested once `total` channels have been received.
+    ///
+    /// # Examples
+    ///
+    /// Print the top 200 vtuber channels by number of subscribers.
+    /// ```rust
+    /// use holodex::model::{builders::ChannelFilterBuilder, ChannelSortingCriteria, Order};
+    ///
+    /// # if std::env::var_os("HOLODEX_API_TOKEN").is_none() {
+    /// #   std::env::set_var("HOLODEX_API_TOKEN", "my-api-token");
+    /// # }
+    /// let token = std::env::var("HOLODEX_API_TOKEN").unwrap();
+    /// let client = holodex::Client::new(&token)?;
+    ///
+    /// let filter = ChannelFilterBuilder::new()
+    ///     .sort_by(ChannelSortingCriteria::SubscriberCount)
+    ///     .order(Order::Descending)
+    ///     .build()?;
+    ///
+    /// for (rank, channel) in client.channels_limited(&filter, 200)?.iter().enumerate() {
+    ///     println!("{}. {}", rank + 1, channel.name);
+    /// }
+    /// # Ok::<(), holodex::errors::Error>(())
+    /// ```
+    ///
+    /// # Errors
+    /// Will return [`Error::ApiRequestFailed`] if sending any of the API requests fails.
+    ///
+    /// Will return [`Error::InvalidResponse`] if the API returned a faulty response or server error.
+    pub fn channels_limited(
+        &self,
+        filter: &ChannelFilter,
+        total: usize,
+    ) -> Result<Vec<Channel>, Error> {
+        self.channels_iter(filter).take(total).collect()
+    }
+
+    #[cfg(feature = "streams")]
+    /// Returns a stream of all channels matching the given filter.
+    ///
+    /// This is the asynchronous counterpart of [`channels_iter`][`Self::channels_iter`],
+    /// and pages through the channels the same way: in pages of 50, starting from the filter's `offset`,
+    /// until a page with fewer than 50 channels is returned.
+    ///
+    /// # Examples
+    ///
+    /// Print the names of all channels from Nijisanji.
+    /// ```rust
+    /// # fn main() -> Result<(), holodex::errors::Error> {
+    /// # tokio_test::block_on(async {
+    /// use holodex::model::{builders::ChannelFilterBuilder, Organisation};
+    /// use futures::{self, pin_mut, StreamExt, TryStreamExt};
+    ///
+    /// # if std::env::var_os("HOLODEX_API_TOKEN").is_none() {
+    /// #   std::env::set_var("HOLODEX_API_TOKEN", "my-api-token");
+    /// # }
+    /// let token = std::env::var("HOLODEX_API_TOKEN").unwrap();
+    /// let client = holodex::Client::new(&token)?;
+    ///
+    /// let filter = ChannelFilterBuilder::new()
+    ///     .organisation(Organisation::Nijisanji)
+    ///     .build()?;
+    ///
+    /// let stream = client.channel_stream(&filter);
+    /// pin_mut!(stream);
+    ///
+    /// while let Some(channel) = stream.try_next().await? {
+    ///     println!("{}", channel.name);
+    /// }
+    /// # Ok(())
+    /// # })
+    /// # }
+    /// ```
+    pub fn channel_stream<'a>(
+        &'a self,
+        filter: &'a ChannelFilter,
+    ) -> impl Stream<Item = Result<Channel, Error>> + 'a {
+        async_stream::try_stream! {
+            let mut pages = ChannelPages::new(filter);
+
+            while let Some(channels) = pages.next_page(self) {
+                for channel in channels? {
+                    yield channel;
+                }
+            }
+        }
+    }
+
+    /// Get every channel known to Holodex.
+    ///
+    /// Channels are sorted by [`Id`][`crate::model::ChannelSortingCriteria::Id`] in ascending order,
+    /// so that the order is stable while paging through them.
+    ///
+    /// **Warning:** This sends one request per 50 channels, which amounts to a large number of requests.
+    /// Prefer [`channels`][`Self::channels`] or [`channels_iter`][`Self::channels_iter`]
+    /// with a filter if you only need a subset of the channels.
+    ///
+    /// # Examples
+    ///
+    /// Count how many channels are marked as inactive.
+    /// ```rust
+    /// # if std::env::var_os("HOLODEX_API_TOKEN").is_none() {
+    /// #   std::env::set_var("HOLODEX_API_TOKEN", "my-api-token");
+    /// # }
+    /// let token = std::env::var("HOLODEX_API_TOKEN").unwrap();
+    /// let client = holodex::Client::new(&token)?;
+    ///
+    /// let channels = client.all_channels()?;
+    /// let inactive = channels.iter().filter(|c| c.inactive).count();
+    ///
+    /// println!("{} out of {} channels are inactive.", inactive, channels.len());
+    /// # Ok::<(), holodex::errors::Error>(())
+    /// ```
+    ///
+    /// # Errors
+    /// Will return [`Error::ApiRequestFailed`] if sending any of the API requests fails.
+    ///
+    /// Will return [`Error::InvalidResponse`] if the API returned a faulty response or server error.
+    pub fn all_channels(&self) -> Result<Vec<Channel>, Error> {
+        self.channels_iter(&ChannelFilter {
+            sort_by: ChannelSortingCriteria::Id,
+            order: Order::Ascending,
+            ..ChannelFilter::default()
+        })
+        .collect()
+    }
+
+    /// Get all topics that videos can be tagged with.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # if std::env::var_os("HOLODEX_API_TOKEN").is_none() {
+    /// #   std::env::set_var("HOLODEX_API_TOKEN", "my-api-token");
+    /// # }
+    /// let token = std::env::var("HOLODEX_API_TOKEN").unwrap();
+    /// let client = holodex::Client::new(&token)?;
+    ///
+    /// for topic in client.topics()? {
+    ///     println!("{}", topic);
+    /// }
+    /// # Ok::<(), holodex::errors::Error>(())
+    /// ```
+    ///
+    /// # Errors
+    /// Will return [`Error::ApiRequestFailed`] if sending the API request fails.
+    ///
+    /// Will return [`Error::InvalidResponse`] if the API returned a faulty response or server error.
+    pub fn topics(&self) -> Result<Vec<Topic>, Error> {
+        let request = self
+            .http
+            .get(&format!("{}/topics", Self::ENDPOINT))
+            .set("x-apikey", &self.token);
+
+        self.send(request, "/topics", None)
+    }
+
+    /// Check if the given topic is known to the API.
+    ///
+    /// Filtering by a topic that does not exist silently returns no videos,
+    /// so this can be used to validate a topic before building a filter with it.
+    ///
+    /// The list of topics is fetched on the first call and cached for the lifetime of the client,
+    /// including any clones of it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # if std::env::var_os("HOLODEX_API_TOKEN").is_none() {
+    /// #   std::env::set_var("HOLODEX_API_TOKEN", "my-api-token");
+    /// # }
+    /// let token = std::env::var("HOLODEX_API_TOKEN").unwrap();
+    /// let client = holodex::Client::new(&token)?;
+    ///
+    /// if !client.topic_exists("singing")? {
+    ///     println!("Unknown topic!");
+    /// }
+    /// # Ok::<(), holodex::errors::Error>(())
+    /// ```
+    ///
+    /// # Errors
+    /// Will return [`Error::ApiRequestFailed`] if sending the API request fails.
+    ///
+    /// Will return [`Error::InvalidResponse`] if the API returned a faulty response or server error.
+    pub fn topic_exists(&self, topic: &str) -> Result<bool, Error> {
+        if let Some(topics) = &*self.topics.read().unwrap_or_else(PoisonError::into_inner) {
+            return Ok(topics.contains(topic));
+        }
+
+        let topics: HashSet<String> = self.topics()?.into_iter().map(|t| t.id).collect();
+        let exists = topics.contains(topic);
+
+        *self.topics.write().unwrap_or_else(PoisonError::into_inner) = Some(topics);
+
+        Ok(exists)
+    }
+
+    /// Get a single video's metadata.
+    ///
+    /// # Examples
+    ///
+    /// Find songs from Coco's graduation stream :(
+    /// ```rust
+    /// # if std::env::var_os("HOLODEX_API_TOKEN").is_none() {
+    /// #   std::env::set_var("HOLODEX_API_TOKEN", "my-api-token");
+    /// # }
+    /// let token = std::env::var("HOLODEX_API_TOKEN").unwrap();
+    /// let client = holodex::Client::new(&token)?;
+    ///
+    /// let coco_graduation = "IhiievWaZMI".parse()?;
+    /// let metadata = client.video(&coco_graduation)?;
+    ///
+    /// for song in &metadata.songs {
+    ///     println!("{}", song);
+    /// }
+    /// # Ok::<(), holodex::errors::Error>(())
+    /// ```
+    ///
+    /// # Errors
+    /// Will return [`Error::ApiRequestFailed`] if sending the API request fails.
+    ///
+    /// Will return [`Error::InvalidResponse`] if the API returned a faulty response or server error.
+    ///
+    /// Will return [`Error::NotFound`] if no video with the given ID exists.
+    pub fn video(&self, video_id: &VideoId) -> Result<VideoFull, Error> {
+        self.get_video::<_, ()>(video_id, None)
+    }
+
+    /// Get a single video's metadata, along with the given extra information.
+    ///
+    /// Only the requested extras are filled in, any other extra fields of [`VideoFull`] are left empty.
+    ///
+    /// # Examples
+    ///
+    /// Get the description and songs of Coco's graduation stream.
+    /// ```rust
+    /// use holodex::model::ExtraVideoInfo;
+    ///
+    /// # if std::env::var_os("HOLODEX_API_TOKEN").is_none() {
+    /// #   std::env::set_var("HOLODEX_API_TOKEN", "my-api-token");
+    /// # }
+    /// let token = std::env::var("HOLODEX_API_TOKEN").unwrap();
+    /// let client = holodex::Client::new(&token)?;
+    ///
+    /// let coco_graduation = "IhiievWaZMI".parse()?;
+    /// let metadata = client.video_with_includes(
+    ///     &coco_graduation,
+    ///     &[ExtraVideoInfo::Description, ExtraVideoInfo::Songs],
+    /// )?;
+    ///
+    /// for song in &metadata.songs {
+    ///     println!("{}", song);
+    /// }
+    /// # Ok::<(), holodex::errors::Error>(())
+    /// ```
+    ///
+    /// # Errors
+    /// Will return [`Error::ApiRequestFailed`] if sending the API request fails.
+    ///
+    /// Will return [`Error::InvalidResponse`] if the API returned a faulty response or server error.
+    ///
+    /// Will return [`Error::NotFound`] if no video with the given ID exists.
+    pub fn video_with_includes(
+        &self,
+        video_id: &VideoId,
+        include: &[ExtraVideoInfo],
+    ) -> Result<VideoFull, Error> {
+        self.get_video(
+            video_id,
+            Some(&[(
+                "include",
+                include
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<String>>()
+                    .join(","),
+            )]),
+        )
+    }
+
+    /// Get only the core metadata of a single video.
+    ///
+    /// This is cheaper than [`video`][`Self::video`] when none of the extra information in
+    /// [`VideoFull`] is needed, such as when checking if a video exists or what its title is.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # if std::env::var_os("HOLODEX_API_TOKEN").is_none() {
+    /// #   std::env::set_var("HOLODEX_API_TOKEN", "my-api-token");
+    /// # }
+    /// let token = std::env::var("HOLODEX_API_TOKEN").unwrap();
+    /// let client = holodex::Client::new(&token)?;
+    ///
+    /// let coco_graduation = "IhiievWaZMI".parse()?;
+    /// let video = client.video_basic(&coco_graduation)?;
+    ///
+    /// println!("{}", video.title);
+    /// # Ok::<(), holodex::errors::Error>(())
+    /// ```
+    ///
+    /// # Errors
+    /// Will return [`Error::ApiRequestFailed`] if sending the API request fails.
+    ///
+    /// Will return [`Error::InvalidResponse`] if the API returned a faulty response or server error.
+    ///
+    /// Will return [`Error::NotFound`] if no video with the given ID exists.
+    pub fn video_basic(&self, video_id: &VideoId) -> Result<Video, Error> {
+        self.get_video::<_, ()>(video_id, None)
+    }
+
+    /// Get a single video's metadata, along with any indexed comments containing timestamps.
+    ///
+    /// The endpoint does not paginate the comments, and has no parameter for doing so.
+    /// Every timestamped comment Holodex has indexed for the video is returned in this one response,
+    /// so there are no further pages to fetch, even for densely timestamped streams.
+    /// Comments without timestamps are not indexed, and are never returned.
+    ///
+    /// # Examples
+    ///
+    /// Find all timestamps for Ollie's birthday stream (in 2021).
+    /// ```rust
+    /// # if std::env::var_os("HOLODEX_API_TOKEN").is_none() {
+    /// #   std::env::set_var("HOLODEX_API_TOKEN", "my-api-token");
+    /// # }
+    /// let token = std::env::var("HOLODEX_API_TOKEN").unwrap();
+    /// let client = holodex::Client::new(&token)?;
+    ///
+    /// let ollie_birthday = "v6o7LBrQs-I".parse()?;
+    /// let metadata = client.video_with_timestamps(&ollie_birthday)?;
+    ///
+    /// for comment in &metadata.comments {
+    ///     println!("{}", comment);
+    /// }
+    /// # Ok::<(), holodex::errors::Error>(())
+    /// ```
+    ///
+    /// # Errors
+    /// Will return [`Error::ApiRequestFailed`] if sending the API request fails.
+    ///
+    /// Will return [`Error::InvalidResponse`] if the API returned a faulty response or server error.
+    ///
+    /// Will return [`Error::NotFound`] if no video with the given ID exists.
+    pub fn video_with_timestamps(&self, video_id: &VideoId) -> Result<VideoFull, Error> {
+        self.get_video(video_id, Some(&[("c", "1")]))
+    }
+
+    /// Get a single video's metadata, along with any indexed comments containing timestamps,
+    /// and any recommended videos in languages matching the given filter.
+    ///
+    /// This combines [`video_with_timestamps`][`Self::video_with_timestamps`] and
+    /// [`video_with_related`][`Self::video_with_related`] into a single request.
+    /// The language filter only applies to the related videos, since comments carry no language
+    /// and the API does not filter them.
+    ///
+    /// An empty `related_language_filter` applies no language filter, the same as passing [`Language::All`].
+    ///
+    /// # Examples
+    ///
+    /// Find all timestamps for Ollie's birthday stream (in 2021), along with related English videos.
+    /// ```rust
+    /// use holodex::model::Language;
+    ///
+    /// # if std::env::var_os("HOLODEX_API_TOKEN").is_none() {
+    /// #   std::env::set_var("HOLODEX_API_TOKEN", "my-api-token");
+    /// # }
+    /// let token = std::env::var("HOLODEX_API_TOKEN").unwrap();
+    /// let client = holodex::Client::new(&token)?;
+    ///
+    /// let ollie_birthday = "v6o7LBrQs-I".parse()?;
+    /// let metadata = client.video_with_timestamps_and_related(&ollie_birthday, &[Language::English])?;
+    ///
+    /// for comment in &metadata.comments {
+    ///     println!("{}", comment);
+    /// }
+    /// for related in &metadata.related {
+    ///     println!("{}", related.title);
+    /// }
+    /// # Ok::<(), holodex::errors::Error>(())
+    /// ```
+    ///
+    /// # Errors
+    /// Will return [`Error::ApiRequestFailed`] if sending the API request fails.
+    ///
+    /// Will return [`Error::InvalidResponse`] if the API returned a faulty response or server error.
+    ///
+    /// Will return [`Error::NotFound`] if no video with the given ID exists.
+    pub fn video_with_timestamps_and_related(
+        &self,
+        video_id: &VideoId,
+        related_language_filter: &[Language],
+    ) -> Result<VideoFull, Error> {
+        let query: Vec<_> = std::iter::once(("c", "1".to_owned()))
+            .chain(Self::related_language_query(related_language_filter))
+            .collect();
+
+        self.get_video(video_id, Some(query.as_slice()))
+    }
+
+    /// Get a single video's metadata, along with any recommended videos in languages matching the given filter.
+    ///
+    /// An empty `related_language_filter` applies no language filter, the same as passing [`Language::All`].
+    ///
+    /// # Examples
+    ///
+    /// Get English videos related to Korone's birthday stream (2021).
+    /// ```rust
+    /// use holodex::model::Language;
+    ///
+    /// # if std::env::var_os("HOLODEX_API_TOKEN").is_none() {
+    /// #   std::env::set_var("HOLODEX_API_TOKEN", "my-api-token");
+    /// # }
+    /// let token = std::env::var("HOLODEX_API_TOKEN").unwrap();
+    /// let client = holodex::Client::new(&token)?;
+    ///
+    /// let korone_birthday = "2l3i7MulCgs-I".parse()?;
+    /// let metadata = client.video_with_related(&korone_birthday, &[Language::English])?;
+    ///
+    /// for related in &metadata.related {
+    ///     println!("{}", related.title);
+    /// }
+    /// # Ok::<(), holodex::errors::Error>(())
+    /// ```
+    ///
+    /// # Errors
+    /// Will return [`Error::ApiRequestFailed`] if sending the API request fails.
+    ///
+    /// Will return [`Error::InvalidResponse`] if the API returned a faulty response or server error.
+    ///
+    /// Will return [`Error::NotFound`] if no video with the given ID exists.
+    pub fn video_with_related(
+        &self,
+        video_id: &VideoId,
+        related_language_filter: &[Language],
+    ) -> Result<VideoFull, Error> {
+        let query: Vec<_> = Self::related_language_query(related_language_filter)
+            .into_iter()
+            .collect();
+
+        self.get_video(video_id, Some(query.as_slice()))
+    }
+
+    /// Search for videos matching the given search conditions.
+    ///
+    /// Searching for `topics` and `clips` together is not supported,
+    /// because clips do not contain `topics`.
+    ///
+    /// The search endpoint does not accept an `include` parameter, but each video in the results
+    /// already contains the metadata of its channel. Use [`search_videos_full`][`Self::search_videos_full`]
+    /// to also keep any other extra information the endpoint returns.
+    ///
+    /// # Examples
+    ///
+    /// Find the five latest Okayu/Korone collab streams.
+    /// ```rust
+    /// use holodex::model::{builders::VideoSearchBuilder, SearchOrder, VideoType};
+    ///
+    /// # if std::env::var_os("HOLODEX_API_TOKEN").is_none() {
+    /// #   std::env::set_var("HOLODEX_API_TOKEN", "my-api-token");
+    /// # }
+    /// let token = std::env::var("HOLODEX_API_TOKEN").unwrap();
+    /// let client = holodex::Client::new(&token)?;
+    ///
+    /// let search = VideoSearchBuilder::new()
+    ///     .order(SearchOrder::Newest)
+    ///     .channels(&["UCvaTdHTWBGv3MKj3KVqJVCw".parse()?, "UChAnqc_AY5_I3Px5dig3X1Q".parse()?])
+    ///     .types(&[VideoType::Stream])
+    ///     .limit(5)
+    ///     .build();
+    ///
+    /// let results = client.search_videos(&search)?;
+    ///
+    /// for result in results {
+    ///     println!("{}", result.title);
+    /// }
+    /// # Ok::<(), holodex::errors::Error>(())
+    /// ```
+    ///
+    /// # Errors
+    /// Will return [`Error::ApiRequestFailed`] if sending the API request fails.
+    ///
+    /// Will return [`Error::InvalidResponse`] if the API returned a faulty response or server error.
+    ///
+    /// Will return [`Error::RequestRejected`] with the sent search parameters if the API rejected them.
+    pub fn search_videos(
+        &self,
+        search_parameters: &VideoSearch,
+    ) -> Result<PaginatedResult<Video>, Error> {
+        let request = self
+            .http
+            .post(&format!("{}/search/videoSearch", Self::ENDPOINT))
+            .set("x-apikey", &self.token);
+        let body = serde_json::to_value(search_parameters)
+            .map_err(|e| Error::FilterCreationError(e.to_string()))?;
+
+        self.send(request, "/search/videoSearch", Some(&body))
+            .map_err(|e| e.with_request_body(&body))
+    }
+
+    /// Search for videos matching the given search conditions, returning the results as [`VideoFull`].
+    ///
+    /// Since the search endpoint does not accept an `include` parameter, only the extra information
+    /// that the endpoint returns by itself is filled in, such as the channels mentioned by clips.
+    /// Any other extra fields are left empty, and have to be fetched with [`video`][`Self::video`].
+    ///
+    /// # Examples
+    ///
+    /// Find the channels mentioned by the latest clips of Pekora.
+    /// ```rust
+    /// use holodex::model::{builders::VideoSearchBuilder, VideoType};
+    ///
+    /// # if std::env::var_os("HOLODEX_API_TOKEN").is_none() {
+    /// #   std::env::set_var("HOLODEX_API_TOKEN", "my-api-token");
+    /// # }
+    /// let token = std::env::var("HOLODEX_API_TOKEN").unwrap();
+    /// let client = holodex::Client::new(&token)?;
+    ///
+    /// let search = VideoSearchBuilder::new()
+    ///     .channels(&["UC1DCedRgGHBdm81E1llLhOQ".parse()?])
+    ///     .types(&[VideoType::Clip])
+    ///     .limit(5)
+    ///     .build();
+    ///
+    /// for clip in client.search_videos_full(&search)? {
+    ///     for channel in &clip.mentions {
+    ///         println!("{} mentions {}", clip.video.title, channel.name);
+    ///     }
+    /// }
+    /// # Ok::<(), holodex::errors::Error>(())
+    /// ```
+    ///
+    /// # Errors
+    /// Will return [`Error::ApiRequestFailed`] if sending the API request fails.
+    ///
+    /// Will return [`Error::InvalidResponse`] if the API returned a faulty response or server error.
+    ///
+    /// Will return [`Error::RequestRejected`] with the sent search parameters if the API rejected them.
+    pub fn search_videos_full(
+        &self,
+        search_parameters: &VideoSearch,
+    ) -> Result<PaginatedResult<VideoFull>, Error> {
+        let request = self
+            .http
+            .post(&format!("{}/search/videoSearch", Self::ENDPOINT))
+            .set("x-apikey", &self.token);
+        let body = serde_json::to_value(search_parameters)
             .map_err(|e| Error::FilterCreationError(e.to_string()))?;
-        let query_pairs: Vec<(&str, String)> = serde_urlencoded::from_str(&query_string)
+
+        self.send(request, "/search/videoSearch", Some(&body))
+            .map_err(|e| e.with_request_body(&body))
+    }
+
+    /// Search for comments matching the given search conditions.
+    ///
+    /// The returned comments carry no language information, since the API does not provide it.
+    /// Any language filter is applied server-side to the videos the comments were left on.
+    ///
+    /// Unlike [`search_videos`][`Self::search_videos`], the results are videos rather than the matches themselves,
+    /// since the API groups the matching comments by the video they were left on,
+    /// with the comments in [`VideoFull::comments`].
+    /// Use [`search_comments_flat`][`Self::search_comments_flat`] to get one result per comment instead.
+    ///
+    /// # Examples
+    ///
+    /// Find the 50 oldest comments containing the word `peko` on streams from Nijisanji.
+    /// ```rust
+    /// use holodex::model::{builders::CommentSearchBuilder, Organisation, SearchOrder, VideoType};
+    ///
+    /// # if std::env::var_os("HOLODEX_API_TOKEN").is_none() {
+    /// #   std::env::set_var("HOLODEX_API_TOKEN", "my-api-token");
+    /// # }
+    /// let token = std::env::var("HOLODEX_API_TOKEN").unwrap();
+    /// let client = holodex::Client::new(&token)?;
+    ///
+    /// let search = CommentSearchBuilder::new("peko")
+    ///     .order(SearchOrder::Oldest)
+    ///     .organisations(&[Organisation::Nijisanji])
+    ///     .types(&[VideoType::Stream])
+    ///     .limit(50)
+    ///     .build();
+    ///
+    /// let videos_with_comments = client.search_comments(&search)?;
+    ///
+    /// for comment in videos_with_comments.into_iter().flat_map(|v| v.comments) {
+    ///     println!("{}", comment);
+    /// }
+    /// # Ok::<(), holodex::errors::Error>(())
+    /// ```
+    ///
+    /// # Errors
+    /// Will return [`Error::ApiRequestFailed`] if sending the API request fails.
+    ///
+    /// Will return [`Error::InvalidResponse`] if the API returned a faulty response or server error.
+    ///
+    /// Will return [`Error::RequestRejected`] with the sent search parameters if the API rejected them.
+    pub fn search_comments(
+        &self,
+        search_parameters: &CommentSearch,
+    ) -> Result<PaginatedResult<VideoFull>, Error> {
+        let request = self
+            .http
+            .post(&format!("{}/search/commentSearch", Self::ENDPOINT))
+            .set("x-apikey", &self.token);
+        let body = serde_json::to_value(search_parameters)
             .map_err(|e| Error::FilterCreationError(e.to_string()))?;
 
-        let mut request = self
+        self.send(request, "/search/commentSearch", Some(&body))
+            .map_err(|e| e.with_request_body(&body))
+    }
+
+    /// Search for comments matching the given search conditions, returning one result per comment.
+    ///
+    /// This sends the same request as [`search_comments`][`Self::search_comments`],
+    /// but flattens the results with [`VideoFull::into_comment_results`],
+    /// so that each comment is paired with the video it was left on.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use holodex::model::builders::CommentSearchBuilder;
+    ///
+    /// # if std::env::var_os("HOLODEX_API_TOKEN").is_none() {
+    /// #   std::env::set_var("HOLODEX_API_TOKEN", "my-api-token");
+    /// # }
+    /// let token = std::env::var("HOLODEX_API_TOKEN").unwrap();
+    /// let client = holodex::Client::new(&token)?;
+    ///
+    /// let search = CommentSearchBuilder::new("peko").limit(50).build();
+    ///
+    /// for result in client.search_comments_flat(&search)? {
+    ///     println!("{}: {}", result.video.title, result.comment);
+    /// }
+    /// # Ok::<(), holodex::errors::Error>(())
+    /// ```
+    ///
+    /// # Errors
+    /// Will return [`Error::ApiRequestFailed`] if sending the API request fails.
+    ///
+    /// Will return [`Error::InvalidResponse`] if the API returned a faulty response or server error.
+    ///
+    /// Will return [`Error::RequestRejected`] with the sent search parameters if the API rejected them.
+    pub fn search_comments_flat(
+        &self,
+        search_parameters: &CommentSearch,
+    ) -> Result<Vec<CommentSearchResult>, Error> {
+        Ok(self
+            .search_comments(search_parameters)?
+            .into_iter()
+            .flat_map(VideoFull::into_comment_results)
+            .collect())
+    }
+
+    /// Send a `GET` request to an arbitrary API endpoint, deserializing the response into `T`.
+    ///
+    /// This is an escape hatch for endpoints that this crate does not support yet.
+    /// The request is authenticated and its response validated the same way as for the other methods,
+    /// but the query parameters are sent as-is, bypassing the type-safe filters.
+    ///
+    /// `path` is relative to the API root, e.g. `/videos`.
+    ///
+    /// # Examples
+    ///
+    /// Get the raw JSON for the two latest videos from Nijisanji.
+    /// ```rust
+    /// # if std::env::var_os("HOLODEX_API_TOKEN").is_none() {
+    /// #   std::env::set_var("HOLODEX_API_TOKEN", "my-api-token");
+    /// # }
+    /// let token = std::env::var("HOLODEX_API_TOKEN").unwrap();
+    /// let client = holodex::Client::new(&token)?;
+    ///
+    /// let videos: serde_json::Value =
+    ///     client.get_json("/videos", &[("org", "Nijisanji"), ("limit", "2")])?;
+    ///
+    /// println!("{}", videos);
+    /// # Ok::<(), holodex::errors::Error>(())
+    /// ```
+    ///
+    /// # Errors
+    /// Will return [`Error::ApiRequestFailed`] if sending the API request fails.
+    ///
+    /// Will return [`Error::InvalidResponse`] if the API returned a faulty response or server error,
+    /// or if the response could not be deserialized into `T`.
+    pub fn get_json<T>(&self, path: &str, query: &[(&str, &str)]) -> Result<T, Error>
+    where
+        T: DeserializeOwned + std::fmt::Debug,
+    {
+        let request = self
+            .http
+            .get(&format!("{}{}", Self::ENDPOINT, path))
+            .set("x-apikey", &self.token)
+            .query_pairs(query.iter().copied());
+
+        self.send(request, path.to_owned(), None)
+    }
+
+    /// Send a `POST` request with a JSON body to an arbitrary API endpoint, deserializing the response into `T`.
+    ///
+    /// This is an escape hatch for endpoints that this crate does not support yet.
+    /// The request is authenticated and its response validated the same way as for the other methods,
+    /// but the body is sent as-is, bypassing the type-safe filters.
+    ///
+    /// `path` is relative to the API root, e.g. `/search/videoSearch`.
+    ///
+    /// # Examples
+    ///
+    /// Search for the five newest clips.
+    /// ```rust
+    /// # if std::env::var_os("HOLODEX_API_TOKEN").is_none() {
+    /// #   std::env::set_var("HOLODEX_API_TOKEN", "my-api-token");
+    /// # }
+    /// let token = std::env::var("HOLODEX_API_TOKEN").unwrap();
+    /// let client = holodex::Client::new(&token)?;
+    ///
+    /// let body = serde_json::json!({
+    ///     "sort": "newest",
+    ///     "target": ["clip"],
+    ///     "paginated": false,
+    ///     "limit": 5,
+    ///     "offset": 0
+    /// });
+    /// let clips: serde_json::Value = client.post_json("/search/videoSearch", &body)?;
+    ///
+    /// println!("{}", clips);
+    /// # Ok::<(), holodex::errors::Error>(())
+    /// ```
+    ///
+    /// # Errors
+    /// Will return [`Error::FilterCreationError`] if `body` could not be serialized.
+    ///
+    /// Will return [`Error::ApiRequestFailed`] if sending the API request fails.
+    ///
+    /// Will return [`Error::InvalidResponse`] if the API returned a faulty response or server error,
+    /// or if the response could not be deserialized into `T`.
+    ///
+    /// Will return [`Error::RequestRejected`] with the sent body if the API rejected it.
+    pub fn post_json<B, T>(&self, path: &str, body: &B) -> Result<T, Error>
+    where
+        B: Serialize + ?Sized,
+        T: DeserializeOwned + std::fmt::Debug,
+    {
+        let request = self
+            .http
+            .post(&format!("{}{}", Self::ENDPOINT, path))
+            .set("x-apikey", &self.token);
+        let body =
+            serde_json::to_value(body).map_err(|e| Error::FilterCreationError(e.to_string()))?;
+
+        self.send(request, path.to_owned(), Some(&body))
+            .map_err(|e| e.with_request_body(&body))
+    }
+
+    fn get_video<R, T>(&self, video_id: &VideoId, query: Option<&T>) -> Result<R, Error>
+    where
+        R: DeserializeOwned + std::fmt::Debug,
+        T: Serialize + Sync + Send + ?Sized + std::fmt::Debug,
+    {
+        let request = self
             .http
             .get(&format!("{}/videos/{}", Self::ENDPOINT, video_id))
             .set("x-apikey", &self.token);
 
-        for (key, value) in query_pairs {
-            request = request.query(key, &value);
-        }
+        self.send(
+            Self::with_query(request, &query)?,
+            "/videos/{video_id}",
+            None,
+        )
+        .map_err(|e| e.or_not_found("video", video_id))
+    }
 
-        let res = request.call().map_err(|e| Error::ApiRequestFailed {
-            endpoint: "/videos/{video_id}",
-            source: e,
-        })?;
+    /// An empty filter leaves out the `lang` parameter, and [`Language::All`] overrides any other languages.
+    fn related_language_query(filter: &[Language]) -> Option<(&'static str, String)> {
+        if filter.is_empty() {
+            return None;
+        }
 
-        let video = validate_response(res).map_err(|e| Error::InvalidResponse {
-            endpoint: "/videos/{video_id}",
-            source: e,
-        })?;
+        let lang = if filter.contains(&Language::All) {
+            Language::All.to_string()
+        } else {
+            filter
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<String>>()
+                .join(",")
+        };
 
-        Ok(video)
+        Some(("lang", lang))
     }
 
-    fn query_videos(
-        http: &ureq::Agent,
-        token: &str,
+    fn query_videos<T>(
+        &self,
         endpoint: &'static str,
         parameters: &VideoFilter,
-    ) -> Result<PaginatedResult<Video>, Error> {
-        let query_string = serde_urlencoded::to_string(parameters)
+    ) -> Result<PaginatedResult<T>, Error>
+    where
+        T: DeserializeOwned + std::fmt::Debug,
+    {
+        let request = self
+            .http
+            .get(&format!("{}{}", Self::ENDPOINT, endpoint))
+            .set("x-apikey", &self.token);
+
+        self.send(Self::with_query(request, parameters)?, endpoint, None)
+    }
+
+    fn with_query<Q>(request: ureq::Request, query: &Q) -> Result<ureq::Request, Error>
+    where
+        Q: Serialize + ?Sized,
+    {
+        Ok(Self::apply_query_pairs(
+            request,
+            &Self::serialize_query(query)?,
+        ))
+    }
+
+    fn serialize_query<Q>(query: &Q) -> Result<Vec<(String, String)>, Error>
+    where
+        Q: Serialize + ?Sized,
+    {
+        let query_string = serde_urlencoded::to_string(query)
             .map_err(|e| Error::FilterCreationError(e.to_string()))?;
-        let query_pairs: Vec<(&str, String)> = serde_urlencoded::from_str(&query_string)
+
+        // Decode the pairs again, since `ureq` encodes them when they are added to the request.
+        let mut pairs: Vec<(String, String)> = serde_urlencoded::from_str(&query_string)
             .map_err(|e| Error::FilterCreationError(e.to_string()))?;
 
-        let mut request = http
-            .get(&format!("{}{}", Self::ENDPOINT, endpoint))
-            .set("x-apikey", token);
+        // Sort by key, so that equal queries always produce the same URL, e.g. for use as a cache key.
+        // The sort is stable, so repeated keys keep their order.
+        pairs.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+        Ok(pairs)
+    }
+
+    fn apply_query_pairs(request: ureq::Request, pairs: &[(String, String)]) -> ureq::Request {
+        pairs
+            .iter()
+            .fold(request, |request, (key, value)| request.query(key, value))
+    }
+
+    fn send<T>(
+        &self,
+        request: ureq::Request,
+        endpoint: impl Into<Cow<'static, str>>,
+        body: Option<&serde_json::Value>,
+    ) -> Result<T, Error>
+    where
+        T: DeserializeOwned + std::fmt::Debug,
+    {
+        let endpoint = endpoint.into();
+
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!(
+            "request",
+            endpoint = &*endpoint,
+            method = request.method(),
+            url = request.url()
+        )
+        .entered();
+
+        #[cfg(feature = "single-flight")]
+        let key = body
+            .is_none()
+            .then(|| request.request_url().ok())
+            .flatten()
+            .map(|url| format!("{} {}", request.method(), url.as_url()));
+
+        #[cfg(feature = "single-flight")]
+        let send = |request| match &key {
+            Some(key) => self
+                .in_flight
+                .run(key.clone(), || self.fetch::<T>(request, &endpoint, None)),
+            None => self.fetch::<T>(request, &endpoint, body).map(Arc::new),
+        };
+        #[cfg(not(feature = "single-flight"))]
+        let send = |request| self.fetch::<T>(request, &endpoint, body);
+
+        // Keep a copy of the request only if it may need to be sent again.
+        let retry = self
+            .incomplete_retry
+            .as_ref()
+            .map(|retry| (retry, request.clone()));
+        let mut bytes = send(request)?;
+
+        if let Some((retry, request)) = retry {
+            for _ in 0..retry.attempts {
+                if !retry.is_incomplete(&bytes) {
+                    break;
+                }
 
-        for (key, value) in query_pairs {
-            request = request.query(key, &value);
+                #[cfg(feature = "tracing")]
+                tracing::warn!(delay = ?retry.delay, "incomplete response, retrying");
+
+                std::thread::sleep(retry.delay);
+                bytes = send(request.clone())?;
+            }
         }
 
-        let res = request.call().map_err(|e| Error::ApiRequestFailed {
-            endpoint,
-            source: e,
-        })?;
+        let result = validate_json_bytes(&bytes).map_err(|e| Error::InvalidResponse {
+            endpoint: endpoint.clone(),
+            source: e.into(),
+        });
 
-        let videos = validate_response(res).map_err(|e| Error::InvalidResponse {
-            endpoint,
-            source: e,
-        })?;
+        #[cfg(feature = "tracing")]
+        if let Err(error) = &result {
+            tracing::error!(%error, "invalid response");
+        }
+
+        result
+    }
+
+    // The endpoint is a `Cow` so that cloning it into errors does not allocate for the built-in endpoints.
+    #[allow(clippy::ptr_arg)]
+    /// Send the request, returning the body of the response.
+    ///
+    /// `T` is the type the response is expected to have, used to parse any error message.
+    fn fetch<T>(
+        &self,
+        request: ureq::Request,
+        endpoint: &Cow<'static, str>,
+        body: Option<&serde_json::Value>,
+    ) -> Result<Vec<u8>, Error>
+    where
+        T: DeserializeOwned + std::fmt::Debug,
+    {
+        let result = response_bytes::<T>(self.respond(request, endpoint, body)?)
+            .map_err(|e| Error::invalid_response(endpoint.clone(), e));
 
-        Ok(videos)
+        #[cfg(feature = "tracing")]
+        if let Err(error) = &result {
+            tracing::error!(%error, "invalid response");
+        }
+
+        result
+    }
+
+    // The endpoint is a `Cow` so that cloning it into errors does not allocate for the built-in endpoints.
+    #[allow(clippy::ptr_arg)]
+    /// Send the request, returning the response without reading its body.
+    fn respond(
+        &self,
+        mut request: ureq::Request,
+        endpoint: &Cow<'static, str>,
+        body: Option<&serde_json::Value>,
+    ) -> Result<ureq::Response, Error> {
+        #[cfg(feature = "tracing")]
+        let start = std::time::Instant::now();
+
+        if let Some(timeout) = self.timeout {
+            request = request.timeout(timeout);
+        }
+
+        let request = request.set("Accept", "application/json");
+        let res = match body {
+            Some(body) => request.send_json(body),
+            None => request.call(),
+        }
+        .map_err(|e| Error::request_failed(endpoint.clone(), e));
+
+        #[cfg(feature = "tracing")]
+        match &res {
+            Ok(response) => tracing::debug!(
+                status = response.status(),
+                elapsed = ?start.elapsed(),
+                "received response"
+            ),
+            Err(error) => tracing::error!(elapsed = ?start.elapsed(), %error, "request failed"),
+        }
+
+        res
     }
 
     #[cfg(feature = "streams")]
-    #[allow(clippy::cast_possible_wrap, clippy::cast_possible_truncation)]
     fn stream_endpoint<'a>(
-        http: &'a ureq::Agent,
-        token: &'a str,
+        &'a self,
         endpoint: &'static str,
         parameters: &'a VideoFilter,
     ) -> impl Stream<Item = Result<Video, Error>> + 'a {
         async_stream::try_stream! {
-            const CHUNK_SIZE: u32 = 50;
-            let mut filter = VideoFilter {
-                paginated: true,
-                limit: CHUNK_SIZE,
-                offset: 0,
-                ..parameters.clone()
-            };
-            let mut counter = 0_u32;
-
-            while let PaginatedResult::Page { total, items } =
-                Self::query_videos(http, token, endpoint, &filter)?
-            {
-                counter += items.len() as u32;
-                let total: u32 = total.into();
-
-                for video in items {
+            let pages = self.stream_endpoint_pages(endpoint, parameters);
+
+            for await page in pages {
+                for video in page? {
                     yield video;
                 }
+            }
+        }
+    }
 
-                if counter >= total {
-                    break;
-                }
+    #[cfg(feature = "streams")]
+    fn stream_endpoint_pages<'a>(
+        &'a self,
+        endpoint: &'static str,
+        parameters: &'a VideoFilter,
+    ) -> impl Stream<Item = Result<PaginatedResult<Video>, Error>> + 'a {
+        async_stream::try_stream! {
+            let mut pages = VideoPages::new(endpoint, parameters);
+
+            while let Some(page) = pages.next_page(self) {
+                yield page?;
+            }
+        }
+    }
+}
+
+/// Fetches consecutive pages of videos from a paginated endpoint,
+/// shared by [`Client::videos_iter`] and the video streams.
+///
+/// Videos are requested in pages of [`PAGE_SIZE`][`Self::PAGE_SIZE`], starting from offset 0,
+/// until the reported total has been received.
+struct VideoPages {
+    endpoint: &'static str,
+    /// The serialized filter without its offset, since only the offset changes between pages.
+    query: Result<Vec<(String, String)>, Option<Error>>,
+    offset: i32,
+    received: usize,
+    finished: bool,
+}
+
+impl VideoPages {
+    const PAGE_SIZE: u32 = 50;
+
+    fn new(endpoint: &'static str, filter: &VideoFilter) -> Self {
+        let query = Client::serialize_query(&VideoFilter {
+            paginated: true,
+            limit: Self::PAGE_SIZE,
+            ..filter.clone()
+        })
+        .map(|mut query| {
+            query.retain(|(key, _)| key != "offset");
+            query
+        });
+
+        Self {
+            endpoint,
+            query: query.map_err(Some),
+            offset: 0,
+            received: 0,
+            finished: false,
+        }
+    }
+
+    /// Fetch the next page.
+    ///
+    /// Returns `None` once every video has been received, or after a request failed.
+    #[allow(clippy::cast_possible_wrap)]
+    fn next_page(&mut self, client: &Client) -> Option<Result<PaginatedResult<Video>, Error>> {
+        if self.finished {
+            return None;
+        }
+
+        let query = match &mut self.query {
+            Ok(query) => query,
+            Err(error) => {
+                self.finished = true;
+                return error.take().map(Err);
+            }
+        };
+
+        let request = Client::apply_query_pairs(
+            client
+                .http
+                .get(&format!("{}{}", Client::ENDPOINT, self.endpoint)),
+            query,
+        )
+        .query("offset", &self.offset.to_string())
+        .set("x-apikey", &client.token);
+
+        let page: PaginatedResult<Video> = match client.send(request, self.endpoint, None) {
+            Ok(page) => page,
+            Err(error) => {
+                self.finished = true;
+                return Some(Err(error));
+            }
+        };
+
+        match &page {
+            // An empty page means the total was off, stop instead of requesting forever.
+            PaginatedResult::Page { items, .. } if items.is_empty() => {
+                self.finished = true;
+                return None;
+            }
+            PaginatedResult::Page { total, items } => {
+                self.received += items.len();
+                self.finished = self.received >= u32::from(*total) as usize;
+            }
+            // The API ignored the pagination and returned everything at once.
+            PaginatedResult::Items(_) => self.finished = true,
+        }
+        self.offset += Self::PAGE_SIZE as i32;
+
+        Some(Ok(page))
+    }
+}
+
+/// Fetches consecutive pages of channels, shared by [`Client::channels_iter`] and [`Client::channel_stream`].
+///
+/// Channels are requested in pages of [`CHANNEL_PAGE_SIZE`][`Client::CHANNEL_PAGE_SIZE`],
+/// starting from the filter's `offset`, until a page with fewer channels is returned.
+struct ChannelPages {
+    filter: ChannelFilter,
+    finished: bool,
+}
+
+impl ChannelPages {
+    fn new(filter: &ChannelFilter) -> Self {
+        Self {
+            filter: ChannelFilter {
+                limit: Client::CHANNEL_PAGE_SIZE,
+                ..filter.clone()
+            },
+            finished: false,
+        }
+    }
+
+    /// Fetch the next page, with the client-side parts of the filter applied.
+    ///
+    /// Returns `None` once the last page has been fetched, or after a request failed.
+    #[allow(clippy::cast_possible_wrap)]
+    fn next_page(&mut self, client: &Client) -> Option<Result<Vec<Channel>, Error>> {
+        if self.finished {
+            return None;
+        }
+
+        match client.fetch_channels(&self.filter) {
+            Ok(mut channels) => {
+                // Channels removed by the client-side filter still count towards a full page.
+                self.finished = channels.len() < Client::CHANNEL_PAGE_SIZE as usize;
+                self.filter.offset += Client::CHANNEL_PAGE_SIZE as i32;
+                Client::retain_channels(&mut channels, &self.filter);
+                Some(Ok(channels))
+            }
+            Err(error) => {
+                self.finished = true;
+                Some(Err(error))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use super::{Client, VideoPages};
+    use crate::model::{id::VideoId, VideoFilter};
+
+    #[test]
+    fn topic_with_reserved_characters_survives_request_url() {
+        let filter = VideoFilter {
+            topic: Some("Minecraft & Chill/ü".to_owned()),
+            ..VideoFilter::default()
+        };
+
+        let request = ureq::get(&format!("{}/videos", Client::ENDPOINT));
+        let request = Client::with_query(request, &filter).unwrap();
 
-                filter.offset += CHUNK_SIZE as i32;
+        assert!(
+            request.url().contains("topic=Minecraft+%26+Chill%2F%C3%BC"),
+            "unexpected url: {}",
+            request.url()
+        );
+
+        let url = request.request_url().unwrap();
+        let topics: Vec<_> = url
+            .query_pairs()
+            .into_iter()
+            .filter(|(key, _)| *key == "topic")
+            .collect();
+        assert_eq!(topics, [("topic", "Minecraft & Chill/ü")]);
+    }
+
+    /// Compares serializing the filter for every page against serializing it once, as [`VideoPages`] does,
+    /// both for the query pairs alone and for the full requests including the URL encoding by `ureq`.
+    ///
+    /// Run with `cargo test --release -- --ignored --nocapture` to see the timings.
+    #[test]
+    #[ignore = "timing measurement, run manually in release mode"]
+    fn serializing_filter_once_is_faster_than_per_page() {
+        const PAGES: i32 = 200;
+
+        fn time<T>(build: impl Fn(i32) -> T) -> Duration {
+            let start = Instant::now();
+            for page in 0_i32..PAGES {
+                std::hint::black_box(build(page * 50_i32));
             }
+            start.elapsed()
         }
+
+        let filter = VideoFilter {
+            id: (0_u32..100)
+                .map(|i| format!("video{i:05}A").parse::<VideoId>().unwrap())
+                .collect(),
+            ..VideoFilter::default()
+        };
+        let url = format!("{}/videos", Client::ENDPOINT);
+        let page_filter = |offset| VideoFilter {
+            paginated: true,
+            limit: VideoPages::PAGE_SIZE,
+            offset,
+            ..filter.clone()
+        };
+
+        let query_per_page = time(|offset| Client::serialize_query(&page_filter(offset)).unwrap());
+        let query_once = {
+            let start = Instant::now();
+            let pages = VideoPages::new("/videos", &filter);
+            std::hint::black_box(pages.query.as_ref().unwrap());
+            start.elapsed() + time(|offset| offset.to_string())
+        };
+
+        let request_per_page =
+            time(|offset| Client::with_query(ureq::get(&url), &page_filter(offset)).unwrap());
+        let request_once = {
+            let start = Instant::now();
+            let pages = VideoPages::new("/videos", &filter);
+            let query = pages.query.as_ref().unwrap();
+            start.elapsed()
+                + time(|offset| {
+                    Client::apply_query_pairs(ureq::get(&url), query)
+                        .query("offset", &offset.to_string())
+                })
+        };
+
+        println!("{PAGES} pages with 100 video IDs:");
+        println!("  query pairs: per page {query_per_page:?}, once {query_once:?}");
+        println!("  requests:    per page {request_per_page:?}, once {request_once:?}");
+        assert!(query_once < query_per_page);
+        assert!(request_once < request_per_page);
     }
 }
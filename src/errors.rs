@@ -1,11 +1,17 @@
 //! Types for various errors that can occur when interacting with the API.
 
+use std::borrow::Cow;
+
 use quick_error::quick_error;
 
 quick_error! {
     #[derive(Debug)]
+    #[non_exhaustive]
     #[allow(missing_docs)]
     /// Errors that can occur when interacting with the Holodex API.
+    ///
+    /// More variants may be added in minor releases, so matches on this need a wildcard arm.
+    /// [`kind`][`Self::kind`] and [`is_transient`][`Self::is_transient`] cover the common cases.
     pub enum Error {
         /// The API token provided to the client is invalid.
         InvalidApiToken {
@@ -17,12 +23,18 @@ quick_error! {
             source(err)
         }
         /// An error occurred while sending a request to the API.
-        ApiRequestFailed { source: ureq::Error, endpoint: &'static str } {
+        ApiRequestFailed { source: ureq::Error, endpoint: Cow<'static, str> } {
             display("Error sending request to {}: {:?}", endpoint, source)
             source(source)
         }
+        /// A request to the API timed out, either while connecting or while reading the response.
+        ///
+        /// See [`Client::with_timeout`][`crate::Client::with_timeout`] for setting the timeout.
+        Timeout { endpoint: Cow<'static, str> } {
+            display("Request to {} timed out", endpoint)
+        }
         /// The API returned a faulty response or server error.
-        InvalidResponse { source: ValidationError, endpoint: &'static str } {
+        InvalidResponse { source: ValidationError, endpoint: Cow<'static, str> } {
             display("Invalid response received from {}: {:?}", endpoint, source)
             source(source)
         }
@@ -34,13 +46,260 @@ quick_error! {
         InvalidChannelId(id: String) {
             display("The provided channel ID was not valid: {}", id)
         }
+        /// A `YouTube` handle or custom URL was given instead of a channel ID.
+        ///
+        /// The Holodex API cannot look up channels by handle, so the channel ID starting with `UC` is needed.
+        UnresolvableChannelHandle(handle: String) {
+            display("{} is a YouTube handle or custom URL, which cannot be resolved. Use the channel ID starting with UC instead.", handle)
+        }
         /// A filter could not be constructed due to invalid arguments.
         FilterCreationError(err: String) {
             display("The filter could not be constructed due to invalid arguments: {}", err)
         }
+        /// The API rejected a request because of its body, such as an invalid search.
+        ///
+        /// Includes at most 2 KiB of the JSON body that was sent.
+        RequestRejected { source: Box<Error>, body: String } {
+            display("{}\nRequest body: {}", source, body)
+            source(&**source)
+        }
+        /// The API could not find the requested resource.
+        NotFound { resource: &'static str, id: String } {
+            display("No {} found with ID {}", resource, id)
+        }
+    }
+}
+
+impl Error {
+    #[allow(clippy::wildcard_enum_match_arm)]
+    /// The HTTP status code the API responded with, if the error was caused by an error response.
+    pub(crate) fn status(&self) -> Option<u16> {
+        match self {
+            Self::RequestRejected { source, .. } => source.status(),
+            Self::ApiRequestFailed {
+                source: ureq::Error::Status(code, _),
+                ..
+            } => Some(*code),
+            Self::InvalidResponse {
+                source: ValidationError::ServerError(error),
+                ..
+            } => Some(error.code()),
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    /// Returns `true` if the error is likely to be temporary, so that sending the same request again may succeed.
+    ///
+    /// | Error | Transient |
+    /// |-------|-----------|
+    /// | Rate limited, with status `429` | Yes |
+    /// | Server errors, with status `5xx` | Yes |
+    /// | Other error statuses, such as `400` or `404` | No |
+    /// | DNS failures, failed or reset connections and timeouts | Yes |
+    /// | Connection lost while reading the response | Yes |
+    /// | Other transport errors, such as invalid URLs or too many redirects | No |
+    /// | Responses that could not be parsed | No |
+    /// | Invalid IDs, filters or API tokens | No |
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use holodex::{errors::Error, model::id::VideoId};
+    ///
+    /// let status = |code| Error::ApiRequestFailed {
+    ///     source: ureq::Error::Status(code, ureq::Response::new(code, "", "").unwrap()),
+    ///     endpoint: "/videos".into(),
+    /// };
+    ///
+    /// assert!(status(429).is_transient());
+    /// assert!(status(503).is_transient());
+    /// assert!(!status(404).is_transient());
+    ///
+    /// let error = "not a video".parse::<VideoId>().unwrap_err();
+    /// assert!(!error.is_transient());
+    /// ```
+    #[allow(clippy::wildcard_enum_match_arm)]
+    pub fn is_transient(&self) -> bool {
+        if let Some(status) = self.status() {
+            return matches!(status, 429 | 500..=599);
+        }
+
+        match self {
+            Self::ApiRequestFailed {
+                source: ureq::Error::Transport(transport),
+                ..
+            } => matches!(
+                transport.kind(),
+                ureq::ErrorKind::Dns
+                    | ureq::ErrorKind::ConnectionFailed
+                    | ureq::ErrorKind::Io
+                    | ureq::ErrorKind::ProxyConnect
+            ),
+            Self::Timeout { .. }
+            | Self::InvalidResponse {
+                source: ValidationError::ParseError(ParseError::ResponseDecodeError(_)),
+                ..
+            } => true,
+            _ => false,
+        }
+    }
+
+    #[must_use]
+    /// The kind of error, without any of the details.
+    pub const fn kind(&self) -> ErrorKind {
+        match self {
+            Self::InvalidApiToken => ErrorKind::InvalidApiToken,
+            Self::HttpClientCreationError(_) => ErrorKind::HttpClientCreationError,
+            Self::ApiRequestFailed { .. } => ErrorKind::ApiRequestFailed,
+            Self::Timeout { .. } => ErrorKind::Timeout,
+            Self::InvalidResponse { .. } => ErrorKind::InvalidResponse,
+            Self::InvalidVideoId(_) => ErrorKind::InvalidVideoId,
+            Self::InvalidChannelId(_) => ErrorKind::InvalidChannelId,
+            Self::UnresolvableChannelHandle(_) => ErrorKind::UnresolvableChannelHandle,
+            Self::FilterCreationError(_) => ErrorKind::FilterCreationError,
+            Self::RequestRejected { .. } => ErrorKind::RequestRejected,
+            Self::NotFound { .. } => ErrorKind::NotFound,
+        }
+    }
+
+    #[must_use]
+    /// Create a summary of the error that can be cloned, for example to hand the same error to several callers.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use holodex::{errors::ErrorKind, model::id::VideoId};
+    ///
+    /// let error = "not a video".parse::<VideoId>().unwrap_err();
+    /// let summary = error.summary();
+    ///
+    /// let shared = vec![summary.clone(), summary];
+    /// assert_eq!(shared[0].kind, ErrorKind::InvalidVideoId);
+    /// assert_eq!(shared[1].message, error.to_string());
+    /// assert_eq!(shared[1].status, None);
+    /// ```
+    pub fn summary(&self) -> ErrorSummary {
+        ErrorSummary {
+            kind: self.kind(),
+            status: self.status(),
+            message: self.to_string(),
+        }
+    }
+
+    /// Create an error for a request that could not be sent, or [`Error::Timeout`] if it timed out.
+    pub(crate) fn request_failed(
+        endpoint: impl Into<Cow<'static, str>>,
+        source: ureq::Error,
+    ) -> Self {
+        let endpoint = endpoint.into();
+
+        if is_timeout(&source) {
+            Self::Timeout { endpoint }
+        } else {
+            Self::ApiRequestFailed { source, endpoint }
+        }
+    }
+
+    /// Create an error for a response that could not be read, or [`Error::Timeout`] if reading it timed out.
+    pub(crate) fn invalid_response(
+        endpoint: impl Into<Cow<'static, str>>,
+        source: ValidationError,
+    ) -> Self {
+        let endpoint = endpoint.into();
+
+        if let ValidationError::ParseError(ParseError::ResponseDecodeError(error)) = &source {
+            if is_timeout(error) {
+                return Self::Timeout { endpoint };
+            }
+        }
+
+        Self::InvalidResponse { source, endpoint }
+    }
+
+    /// Attach the request body to client errors, since those are caused by what was sent.
+    pub(crate) fn with_request_body(self, body: &serde_json::Value) -> Self {
+        if matches!(self.status(), Some(400..=499)) {
+            Self::RequestRejected {
+                source: Box::new(self),
+                body: crate::util::body_excerpt(body),
+            }
+        } else {
+            self
+        }
+    }
+
+    /// Replace a 404 error with [`Error::NotFound`] for the given resource.
+    pub(crate) fn or_not_found(self, resource: &'static str, id: &str) -> Self {
+        if self.status() == Some(404) {
+            Self::NotFound {
+                resource,
+                id: id.to_owned(),
+            }
+        } else {
+            self
+        }
+    }
+}
+
+/// Returns `true` if the error, or any error that caused it, is an I/O error that timed out.
+fn is_timeout(error: &(dyn std::error::Error + 'static)) -> bool {
+    std::iter::successors(Some(error), |error| error.source()).any(|error| {
+        error
+            .downcast_ref::<std::io::Error>()
+            .is_some_and(|error| error.kind() == std::io::ErrorKind::TimedOut)
+    })
+}
+
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// The kinds of [`Error`] that can occur, one for each variant.
+pub enum ErrorKind {
+    /// See [`Error::InvalidApiToken`].
+    InvalidApiToken,
+    /// See [`Error::HttpClientCreationError`].
+    HttpClientCreationError,
+    /// See [`Error::ApiRequestFailed`].
+    ApiRequestFailed,
+    /// See [`Error::Timeout`].
+    Timeout,
+    /// See [`Error::InvalidResponse`].
+    InvalidResponse,
+    /// See [`Error::InvalidVideoId`].
+    InvalidVideoId,
+    /// See [`Error::InvalidChannelId`].
+    InvalidChannelId,
+    /// See [`Error::UnresolvableChannelHandle`].
+    UnresolvableChannelHandle,
+    /// See [`Error::FilterCreationError`].
+    FilterCreationError,
+    /// See [`Error::RequestRejected`].
+    RequestRejected,
+    /// See [`Error::NotFound`].
+    NotFound,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+/// A summary of an [`Error`] that, unlike the error itself, can be cloned and shared.
+///
+/// Created with [`Error::summary`].
+pub struct ErrorSummary {
+    /// What kind of error occurred.
+    pub kind: ErrorKind,
+    /// The HTTP status code the API responded with, if the error was caused by an error response.
+    pub status: Option<u16>,
+    /// The message of the error, as displayed by the original error.
+    pub message: String,
+}
+
+impl std::fmt::Display for ErrorSummary {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.pad(&self.message)
     }
 }
 
+impl std::error::Error for ErrorSummary {}
+
 quick_error! {
     #[derive(Debug)]
     /// Errors that can occur when validating a response from the Holodex API.
@@ -79,6 +338,17 @@ quick_error! {
     }
 }
 
+impl ServerError {
+    /// The HTTP status code the API responded with.
+    pub(crate) const fn code(&self) -> u16 {
+        match *self {
+            Self::ErrorCode(code)
+            | Self::ErrorCodeWithValue(code, _)
+            | Self::ErrorCodeWithValueParseError(code, _) => code,
+        }
+    }
+}
+
 quick_error! {
     #[derive(Debug)]
     /// Errors that occur when parsing a response from the API.
@@ -88,18 +358,31 @@ quick_error! {
             display("Could not decode response: {}", err)
             source(err)
         }
+        /// The response from the API was larger than the maximum allowed size.
+        ResponseTooLarge(limit: usize) {
+            display("Response exceeded the maximum size of {} bytes", limit)
+        }
         /// The response from the API lacked a header.
         MissingHeader(header: &'static str) {
             display("Response lacked header: {}", header)
         }
         /// The response from the API could not be parsed as JSON.
-        ResponseJsonParseError(err: serde_json::Error, response: String) {
-            display("Failed to parse response as JSON: {}\nResponse: {}", err, response)
+        ///
+        /// Includes an excerpt of at most 2 KiB of the response, around where parsing failed.
+        ResponseJsonParseError(err: serde_json::Error, excerpt: String) {
+            display("Failed to parse response as JSON: {}\nResponse: {}", err, excerpt)
             source(err)
         }
         /// The response from the API could not be parsed.
-        ResponseParseError(err: serde_json::Error, response: serde_json::Value) {
-            display("Failed to parse response: {}\nResponse: {}", err, response)
+        ///
+        /// Includes an excerpt of at most 2 KiB of the response, around where parsing failed.
+        ResponseParseError(err: serde_json::Error, excerpt: String) {
+            display("Failed to parse response: {}\nResponse: {}", err, excerpt)
+            source(err)
+        }
+        /// A JSON value could not be parsed into a model type.
+        ValueParseError(err: serde_json::Error) {
+            display("Failed to parse JSON value: {}", err)
             source(err)
         }
         /// The response from the API could not be parsed as JSON or UTF-8.
@@ -37,7 +37,6 @@
     clippy::rest_pat_in_fully_bound_structs,
     clippy::semicolon_if_nothing_returned,
     clippy::str_to_string,
-    clippy::string_to_string,
     clippy::todo,
     clippy::unimplemented,
     clippy::unneeded_field_pattern,
@@ -51,15 +50,55 @@
 #![allow(
     clippy::non_ascii_literal,
     clippy::cargo_common_metadata,
-    clippy::multiple_crate_versions
+    clippy::multiple_crate_versions,
+    // `Error` holds `ureq::Error` by value, which is close to 300 bytes, so every fallible function trips this.
+    // Boxing it would change the public variants, so the size is accepted until the next breaking release.
+    clippy::result_large_err,
+    clippy::literal_string_with_formatting_args
 )]
 
 //! Rust wrapper for the Holodex v2 API.
+//!
+//! # Features
+//!
+//! - `streams` *(default)*: Enables methods returning asynchronous streams of paginated results.
+//! - `sso` *(default)*: Stores IDs using small string optimization.
+//! - `rustls` *(default)*: Uses [`rustls`](https://docs.rs/rustls) with bundled root certificates for TLS.
+//! - `native-tls`: Uses the platform's native TLS implementation and certificate store instead.
+//!   This takes precedence over `rustls` when both are enabled, so `rustls` is only compiled in but unused.
+//!   Disable default features to leave it out.
+//! - `tracing`: Emits a [`tracing`](https://docs.rs/tracing) span for every request sent to the API,
+//!   with the endpoint, URL, response status and elapsed time logged at the `debug` level,
+//!   and failures logged at the `error` level.
+//! - `extra-fields`: Captures any fields returned by the API that are not modeled yet
+//!   in an `extra` field on [`Video`](model::Video) and [`Channel`](model::Channel).
+//! - `single-flight`: Makes identical `GET` requests that are sent at the same time, from clones of the same
+//!   [`Client`], share a single request to the API. Callers waiting on a request that fails send their own.
+//! - `debug-raw`: Adds methods such as `Client::videos_with_raw` that return the raw JSON body
+//!   of the response alongside the parsed value, to help debug responses that parse unexpectedly.
 
 pub mod errors;
 pub mod model;
 
 mod client;
+#[cfg(feature = "single-flight")]
+mod single_flight;
 mod util;
 
 pub use client::Client;
+
+// Compile-time guarantee that the client and the types it returns can be shared between threads.
+const _: () = {
+    const fn assert_send_sync<T: Send + Sync>() {}
+
+    assert_send_sync::<Client>();
+    assert_send_sync::<errors::Error>();
+    assert_send_sync::<model::Video>();
+    assert_send_sync::<model::VideoFull>();
+    assert_send_sync::<model::Channel>();
+    assert_send_sync::<model::Comment>();
+    assert_send_sync::<model::PaginatedResult<model::Video>>();
+    assert_send_sync::<model::VideoFilter>();
+    assert_send_sync::<model::id::VideoId>();
+    assert_send_sync::<model::id::ChannelId>();
+};
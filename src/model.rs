@@ -2,28 +2,54 @@
 #![allow(clippy::use_self)]
 
 pub mod builders;
+pub mod dedup;
 pub mod id;
 
 mod serializers;
 
 use std::{
+    cmp::Ordering,
+    collections::HashSet,
     fmt::{self, Display},
     ops::Deref,
     string::ToString,
 };
 
 use chrono::{DateTime, Duration, Utc};
-use serde::{self, Deserialize, Serialize};
+use regex::Regex;
+use serde::{self, de::DeserializeOwned, Deserialize, Serialize};
 use serde_with::{
     formats::CommaSeparator, As, DisplayFromStr, DurationSeconds, StringWithSeparator,
 };
 
-use crate::util::is_default;
+use crate::{
+    errors::{Error, ParseError},
+    util::is_default,
+};
 
 use self::id::{ChannelId, VideoId};
 
 #[derive(Serialize, Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
 /// Filtering criteria for the various video endpoints.
+///
+/// The filters created by [`default`][`Self::default`] and [`all`][`Self::all`] have these values,
+/// note that the default filter only returns videos from Hololive:
+///
+/// | Parameter          | `default()` | `all()` |
+/// |--------------------|-------------|---------|
+/// | Organisation       | [`Hololive`][`Organisation::Hololive`] | Any |
+/// | Languages          | [[`All`][`Language::All`]] | [[`All`][`Language::All`]] |
+/// | Status             | Any         | [`VideoStatus::active_statuses`] |
+/// | Include            | [[`LiveInfo`][`ExtraVideoInfo::LiveInfo`]] | Nothing |
+/// | Video type         | [`Stream`][`VideoType::Stream`] | [`Stream`][`VideoType::Stream`] |
+/// | Sort by            | [`AvailableAt`][`VideoSortingCriteria::AvailableAt`] | [`AvailableAt`][`VideoSortingCriteria::AvailableAt`] |
+/// | Order              | [`Descending`][`Order::Descending`] | [`Descending`][`Order::Descending`] |
+/// | Max upcoming hours | 48          | 48      |
+/// | Paginated          | `true`      | `true`  |
+/// | Limit              | 100         | 100     |
+/// | Offset             | 0           | 0       |
+///
+/// Every other parameter is unset in both.
 pub struct VideoFilter {
     /// Only return videos from that channel.
     pub channel_id: Option<ChannelId>,
@@ -42,20 +68,106 @@ pub struct VideoFilter {
     /// If only videos of a specific [`Language`] should be returned.
     pub lang: Vec<Language>,
     /// Max amount of hours in the future to return videos from. Videos scheduled further in the future will not be returned.
-    pub max_upcoming_hours: u32,
+    ///
+    /// `Some(0)` excludes all upcoming videos, while `None` leaves the limit up to the API.
+    pub max_upcoming_hours: Option<u32>,
     /// If only videos mentioning a specific channel should be returned.
+    ///
+    /// Combined with [`channel_id`][`Self::channel_id`], this finds collabs hosted by one channel with another.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use holodex::model::VideoFilter;
+    ///
+    /// let filter = VideoFilter {
+    ///     channel_id: Some("UCHsx4Hqa-1ORjQTh9TYDhww".parse()?),
+    ///     mentioned_channel_id: Some("UCL_qhgtOy0dy1Agp8vkySQg".parse()?),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let query = serde_urlencoded::to_string(&filter).unwrap();
+    /// let pairs: Vec<_> = query.split('&').collect();
+    ///
+    /// assert!(pairs.contains(&"channel_id=UCHsx4Hqa-1ORjQTh9TYDhww"));
+    /// assert!(pairs.contains(&"mentioned_channel_id=UCL_qhgtOy0dy1Agp8vkySQg"));
+    /// # Ok::<(), holodex::errors::Error>(())
+    /// ```
     pub mentioned_channel_id: Option<ChannelId>,
-    #[serde(with = "As::<StringWithSeparator::<CommaSeparator, _>>")]
+    #[serde(serialize_with = "serializers::known_statuses")]
     #[serde(skip_serializing_if = "Vec::is_empty")]
     /// Which statuses the videos should have.
+    ///
+    /// If empty, videos of every status are returned, including [`New`][`VideoStatus::New`]
+    /// and [`Missing`][`VideoStatus::Missing`] ones. Use [`VideoStatus::active_statuses`]
+    /// to only get videos that are available.
+    ///
+    /// [`VideoStatus::Unknown`] cannot be sent, so requests with it fail with [`Error::FilterCreationError`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use holodex::model::{VideoFilter, VideoStatus};
+    ///
+    /// let filter = VideoFilter {
+    ///     status: vec![VideoStatus::Live, VideoStatus::Unknown],
+    ///     ..Default::default()
+    /// };
+    /// assert!(serde_urlencoded::to_string(&filter).is_err());
+    ///
+    /// let filter = VideoFilter {
+    ///     status: vec![VideoStatus::Live, VideoStatus::Upcoming],
+    ///     ..Default::default()
+    /// };
+    /// let query = serde_urlencoded::to_string(&filter).unwrap();
+    /// assert!(query.split('&').any(|pair| pair == "status=live%2Cupcoming"));
+    /// ```
     pub status: Vec<VideoStatus>,
     /// A topic that the videos should be related to.
+    ///
+    /// The topic is URL-encoded exactly once when sent, so it should be given as-is.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use holodex::model::VideoFilter;
+    ///
+    /// let filter = VideoFilter {
+    ///     topic: Some("Minecraft & Chill/ü".to_owned()),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let query = serde_urlencoded::to_string(&filter)?;
+    /// assert!(query.split('&').any(|pair| pair == "topic=Minecraft+%26+Chill%2F%C3%BC"));
+    ///
+    /// // Decoding the query once yields the original topic, so it was not encoded twice.
+    /// let pairs: Vec<(String, String)> = serde_urlencoded::from_str(&query)?;
+    /// assert!(pairs.contains(&("topic".to_owned(), "Minecraft & Chill/ü".to_owned())));
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
     pub topic: Option<String>,
     #[serde(rename = "type")]
     /// The type of the videos.
     pub video_type: VideoType,
 
+    #[serde(serialize_with = "serializers::rfc3339_seconds")]
     /// Only include videos with `available_at` later than this time.
+    ///
+    /// The time is sent to the API as an RFC 3339 timestamp in UTC, truncated to whole seconds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::{TimeZone, Utc};
+    /// use holodex::model::builders::VideoFilterBuilder;
+    ///
+    /// let after = Utc.with_ymd_and_hms(2021, 9, 1, 12, 30, 0).unwrap()
+    ///     + chrono::Duration::milliseconds(250);
+    /// let filter = VideoFilterBuilder::new().after(after).build();
+    ///
+    /// let query = serde_urlencoded::to_string(&filter).unwrap();
+    /// assert!(query.contains("from=2021-09-01T12%3A30%3A00Z"));
+    /// ```
     pub from: Option<DateTime<Utc>>,
 
     #[serde(with = "As::<DisplayFromStr>")]
@@ -81,6 +193,133 @@ impl VideoFilter {
     pub fn new() -> Self {
         Self::default()
     }
+
+    #[must_use]
+    /// Create a new `VideoFilter` that is not limited to any organisation,
+    /// and only returns videos that are available or scheduled to be.
+    ///
+    /// Unlike [`default`][`Self::default`], which only returns videos from Hololive,
+    /// this queries videos from every organisation, see [`VideoFilter`] for how the two differ.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use holodex::model::{Language, VideoFilter, VideoStatus};
+    ///
+    /// let filter = VideoFilter::all();
+    ///
+    /// assert_eq!(filter.org, None);
+    /// assert_eq!(filter.lang, [Language::All]);
+    /// assert_eq!(filter.status, VideoStatus::active_statuses());
+    /// assert!(filter.include.is_empty());
+    ///
+    /// let query = serde_urlencoded::to_string(&filter).unwrap();
+    /// assert!(!query.contains("org="));
+    /// ```
+    pub fn all() -> Self {
+        Self {
+            org: None,
+            lang: vec![Language::All],
+            status: VideoStatus::active_statuses().to_vec(),
+            include: Vec::new(),
+            ..Self::default()
+        }
+    }
+
+    #[must_use]
+    /// Get a key identifying which videos the filter matches, ignoring which page of them is requested.
+    ///
+    /// The key is a copy of the filter with [`paginated`][`Self::paginated`], [`limit`][`Self::limit`]
+    /// and [`offset`][`Self::offset`] cleared, so two filters that only differ in those have equal keys.
+    /// This is useful for caching the results of a query while paging through them.
+    /// The key is not meant to be sent as a filter itself.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::collections::HashMap;
+    /// use holodex::model::VideoFilter;
+    ///
+    /// let first_page = VideoFilter::default();
+    /// let second_page = VideoFilter { offset: 100, ..VideoFilter::default() };
+    ///
+    /// assert_ne!(first_page, second_page);
+    /// assert_eq!(first_page.semantic_key(), second_page.semantic_key());
+    ///
+    /// let mut cache = HashMap::new();
+    /// cache.insert(first_page.semantic_key(), "cached results");
+    /// assert!(cache.contains_key(&second_page.semantic_key()));
+    /// assert!(!cache.contains_key(&VideoFilter::all().semantic_key()));
+    /// ```
+    pub fn semantic_key(&self) -> Self {
+        Self {
+            paginated: false,
+            limit: 0,
+            offset: 0,
+            ..self.clone()
+        }
+    }
+
+    /// Check that the filter does not combine options that cannot produce meaningful results.
+    ///
+    /// Currently this rejects sorting clips by criteria that only apply to livestreams,
+    /// such as [`LiveViewers`][`VideoSortingCriteria::LiveViewers`] or
+    /// [`StartActual`][`VideoSortingCriteria::StartActual`], since clips have no values for them.
+    /// It also rejects [`VideoType::Unknown`] and [`VideoStatus::Unknown`],
+    /// which only stand in for values the API sent that this crate does not know about.
+    /// Combinations that could be intentional are allowed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use holodex::model::{VideoFilter, VideoSortingCriteria, VideoType};
+    ///
+    /// let clips_by_viewers = VideoFilter {
+    ///     video_type: VideoType::Clip,
+    ///     sort_by: VideoSortingCriteria::LiveViewers,
+    ///     ..Default::default()
+    /// };
+    /// assert!(clips_by_viewers.validate().is_err());
+    ///
+    /// let unknown_type = VideoFilter {
+    ///     video_type: VideoType::Unknown,
+    ///     ..Default::default()
+    /// };
+    /// assert!(unknown_type.validate().is_err());
+    ///
+    /// let streams_by_viewers = VideoFilter {
+    ///     video_type: VideoType::Stream,
+    ///     ..clips_by_viewers
+    /// };
+    /// assert!(streams_by_viewers.validate().is_ok());
+    /// ```
+    ///
+    /// # Errors
+    /// Will return [`Error::FilterCreationError`] if the filter combines incompatible options.
+    pub fn validate(&self) -> Result<(), Error> {
+        if self.video_type == VideoType::Unknown || self.status.contains(&VideoStatus::Unknown) {
+            return Err(Error::FilterCreationError(
+                "Unknown video types and statuses cannot be used in filters.".to_owned(),
+            ));
+        }
+
+        let stream_only = matches!(
+            self.sort_by,
+            VideoSortingCriteria::StartScheduled
+                | VideoSortingCriteria::StartActual
+                | VideoSortingCriteria::EndActual
+                | VideoSortingCriteria::LiveViewers
+        );
+
+        if self.video_type == VideoType::Clip && stream_only {
+            return Err(Error::FilterCreationError(format!(
+                "Could not sort clips by {}, as it only applies to streams.",
+                self.sort_by
+            )));
+        }
+
+        Ok(())
+    }
 }
 
 impl Default for VideoFilter {
@@ -91,7 +330,7 @@ impl Default for VideoFilter {
             include: vec![ExtraVideoInfo::LiveInfo],
             lang: vec![Language::All],
             limit: 100,
-            max_upcoming_hours: 48,
+            max_upcoming_hours: Some(48),
             mentioned_channel_id: None,
             offset: 0,
             order: Order::Descending,
@@ -107,7 +346,7 @@ impl Default for VideoFilter {
 }
 
 impl Display for VideoFilter {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
             f,
             "{} {{
@@ -137,7 +376,7 @@ impl Display for VideoFilter {
                 .join(", "),
             self.org
                 .as_ref()
-                .map_or("None".to_owned(), ToString::to_string),
+                .map_or_else(|| "None".to_owned(), ToString::to_string),
             self.include
                 .iter()
                 .map(ToString::to_string)
@@ -148,7 +387,8 @@ impl Display for VideoFilter {
                 .map(ToString::to_string)
                 .collect::<Vec<String>>()
                 .join(", "),
-            self.max_upcoming_hours,
+            self.max_upcoming_hours
+                .map_or_else(|| "None".to_owned(), |hours| hours.to_string()),
             self.mentioned_channel_id
                 .as_ref()
                 .map_or("None", |id| &*id.0),
@@ -164,11 +404,11 @@ impl Display for VideoFilter {
                 .join(", "),
             self.topic
                 .as_ref()
-                .map_or("None".to_owned(), ToString::to_string),
+                .map_or_else(|| "None".to_owned(), ToString::to_string),
             self.video_type,
             self.from
                 .as_ref()
-                .map_or("None".to_owned(), ToString::to_string),
+                .map_or_else(|| "None".to_owned(), ToString::to_string),
         )
     }
 }
@@ -218,7 +458,7 @@ impl Default for ChannelVideoFilter {
 }
 
 impl Display for ChannelVideoFilter {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
             f,
             "{} {{ include: {}, lang: {}, paginated: {}, limit: {}, offset: {} }}",
@@ -242,8 +482,27 @@ impl Display for ChannelVideoFilter {
 
 #[derive(Serialize, Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
 /// Filtering criteria for channels.
+///
+/// The default filter has these values:
+///
+/// | Parameter    | Default |
+/// |--------------|---------|
+/// | Languages    | Any     |
+/// | Sort by      | [`Organisation`][`ChannelSortingCriteria::Organisation`] |
+/// | Order        | [`Ascending`][`Order::Ascending`] |
+/// | Organisation | Any     |
+/// | Channel type | Any     |
+/// | Limit        | 25      |
+/// | Offset       | 0       |
+/// | Exclude inactive | `false` |
+///
+/// Note that this means only the first 25 channels are returned by default,
+/// use [`Client::channels_limited`][`crate::Client::channels_limited`],
+/// [`Client::channels_iter`][`crate::Client::channels_iter`] or
+/// [`Client::all_channels`][`crate::Client::all_channels`] to get more.
 pub struct ChannelFilter {
     #[serde(rename = "lang")]
+    #[serde(with = "As::<StringWithSeparator::<CommaSeparator, _>>")]
     #[serde(skip_serializing_if = "Vec::is_empty")]
     /// Only show channels that uses any of the given languages as their main language.
     pub languages: Vec<Language>,
@@ -266,6 +525,32 @@ pub struct ChannelFilter {
     pub limit: u32,
     /// Offset the returned values by the given amount of places.
     pub offset: i32,
+
+    #[serde(skip)]
+    /// Leave out channels that are marked as [`inactive`][`Channel::inactive`], such as graduated talents.
+    ///
+    /// The API has no way of filtering by this, so it is done client-side after each page is received.
+    /// This means that [`Client::channels`][`crate::Client::channels`] can return fewer channels than the `limit`,
+    /// while the `offset` still counts the inactive channels that were left out.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use holodex::model::{builders::ChannelFilterBuilder, ChannelSortingCriteria};
+    ///
+    /// let filter = ChannelFilterBuilder::new()
+    ///     .sort_by(ChannelSortingCriteria::Inactive)
+    ///     .exclude_inactive()
+    ///     .build()?;
+    ///
+    /// let query = serde_urlencoded::to_string(&filter).unwrap();
+    ///
+    /// assert!(filter.exclude_inactive);
+    /// assert!(query.contains("sort=inactive"));
+    /// assert!(!query.contains("exclude"));
+    /// # Ok::<(), holodex::errors::Error>(())
+    /// ```
+    pub exclude_inactive: bool,
 }
 
 impl Default for ChannelFilter {
@@ -278,12 +563,19 @@ impl Default for ChannelFilter {
             channel_type: None,
             limit: 25,
             offset: 0,
+            exclude_inactive: false,
         }
     }
 }
 
 #[derive(Serialize, Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
 /// Filtering criteria for video searches.
+///
+/// Unlike [`VideoFilter`], there is no `include` field, since the search endpoint does not accept one.
+/// Search results carry the metadata of their channels, and
+/// [`Client::search_videos_full`][`crate::Client::search_videos_full`] keeps any other extra information
+/// the endpoint returns. Anything else, such as descriptions, has to be fetched with
+/// [`Client::video`][`crate::Client::video`].
 pub struct VideoSearch {
     #[serde(rename = "sort")]
     /// In what order the videos should be returned.
@@ -318,6 +610,20 @@ pub struct VideoSearch {
     /// Only return videos from channels in the given organisation,
     /// or are clips from a channel in the organisation.
     pub organisations: Vec<Organisation>,
+    #[serde(serialize_with = "serializers::rfc3339_seconds")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// Only return videos with `available_at` at or after this time.
+    ///
+    /// Serialized as the `from` key of the search body, as an RFC 3339 timestamp in UTC with whole seconds.
+    /// See [`VideoSearchBuilder::between`][`crate::model::builders::VideoSearchBuilder::between`] for the full body.
+    pub from: Option<DateTime<Utc>>,
+    #[serde(serialize_with = "serializers::rfc3339_seconds")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// Only return videos with `available_at` at or before this time.
+    ///
+    /// Serialized as the `to` key of the search body, as an RFC 3339 timestamp in UTC with whole seconds.
+    /// See [`VideoSearchBuilder::between`][`crate::model::builders::VideoSearchBuilder::between`] for the full body.
+    pub to: Option<DateTime<Utc>>,
 
     #[serde(with = "As::<DisplayFromStr>")]
     #[serde(skip_serializing_if = "is_default")]
@@ -340,6 +646,8 @@ impl Default for VideoSearch {
             topics: Vec::default(),
             channels: Vec::default(),
             organisations: Vec::default(),
+            from: None,
+            to: None,
             paginated: true,
             limit: 30,
             offset: 0,
@@ -347,6 +655,87 @@ impl Default for VideoSearch {
     }
 }
 
+impl VideoSearch {
+    #[must_use]
+    /// Get a key identifying which videos the search matches, ignoring which page of them is requested.
+    ///
+    /// The key is a copy of the search with [`paginated`][`Self::paginated`], [`limit`][`Self::limit`]
+    /// and [`offset`][`Self::offset`] cleared, see [`VideoFilter::semantic_key`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use holodex::model::{SearchOrder, VideoSearch};
+    ///
+    /// let first_page = VideoSearch::default();
+    /// let second_page = VideoSearch { offset: 30, ..VideoSearch::default() };
+    /// let oldest = VideoSearch { sort_order: SearchOrder::Oldest, ..VideoSearch::default() };
+    ///
+    /// assert_eq!(first_page.semantic_key(), second_page.semantic_key());
+    /// assert_ne!(first_page.semantic_key(), oldest.semantic_key());
+    /// ```
+    pub fn semantic_key(&self) -> Self {
+        Self {
+            paginated: false,
+            limit: 0,
+            offset: 0,
+            ..self.clone()
+        }
+    }
+
+    /// Check that the search does not combine options that cannot produce meaningful results.
+    ///
+    /// Currently this rejects time windows where [`from`][`Self::from`] is after [`to`][`Self::to`],
+    /// and searching for topics when only clips are searched for, since clips do not have topics.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::{TimeZone, Utc};
+    /// use holodex::model::{VideoSearch, VideoType};
+    ///
+    /// let backwards = VideoSearch {
+    ///     from: Some(Utc.with_ymd_and_hms(2021, 3, 31, 0, 0, 0).unwrap()),
+    ///     to: Some(Utc.with_ymd_and_hms(2021, 3, 1, 0, 0, 0).unwrap()),
+    ///     ..Default::default()
+    /// };
+    /// assert!(backwards.validate().is_err());
+    ///
+    /// let clips_with_topics = VideoSearch {
+    ///     types: vec![VideoType::Clip],
+    ///     topics: vec!["singing".to_owned()],
+    ///     ..Default::default()
+    /// };
+    /// assert!(clips_with_topics.validate().is_err());
+    ///
+    /// let streams_with_topics = VideoSearch {
+    ///     types: vec![VideoType::Stream],
+    ///     ..clips_with_topics
+    /// };
+    /// assert!(streams_with_topics.validate().is_ok());
+    /// ```
+    ///
+    /// # Errors
+    /// Will return [`Error::FilterCreationError`] if the search combines incompatible options.
+    pub fn validate(&self) -> Result<(), Error> {
+        if let (Some(from), Some(to)) = (self.from, self.to) {
+            if from > to {
+                return Err(Error::FilterCreationError(format!(
+                    "Could not search for videos from {from} to {to}, as the start is after the end."
+                )));
+            }
+        }
+
+        if self.types == [VideoType::Clip] && !self.topics.is_empty() {
+            return Err(Error::FilterCreationError(
+                "Could not search for clips by topic, as clips do not have topics.".to_owned(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
 #[derive(Serialize, Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
 #[serde(rename_all(serialize = "snake_case"))]
 /// A condition that a video must meet to be eligible.
@@ -359,8 +748,11 @@ pub enum VideoSearchCondition {
 /// Filtering criteria for comment searches.
 pub struct CommentSearch {
     #[serde(rename = "comment")]
-    /// Only return comments that include the given substring.
-    pub search: String,
+    /// The substrings to search comments for, sent as the `comment` list.
+    ///
+    /// The API only documents searching for a single substring,
+    /// so how it combines several terms, and whether matching is case-sensitive, is unspecified.
+    pub search: Vec<String>,
     #[serde(rename = "sort")]
     /// In what order the comments should be returned.
     pub sort_order: SearchOrder,
@@ -405,10 +797,20 @@ pub struct CommentSearch {
     pub offset: i32,
 }
 
+/// Search for comments that include the given substring, with default values otherwise.
+impl From<&str> for CommentSearch {
+    fn from(search: &str) -> Self {
+        Self {
+            search: vec![search.to_owned()],
+            ..Self::default()
+        }
+    }
+}
+
 impl Default for CommentSearch {
     fn default() -> Self {
         Self {
-            search: String::default(),
+            search: Vec::default(),
             sort_order: SearchOrder::Newest,
             languages: Vec::default(),
             types: Vec::default(),
@@ -476,6 +878,23 @@ impl Display for ExtraVideoInfo {
 #[non_exhaustive]
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 /// What language to filter videos by.
+///
+/// When parsed, any region subtag is ignored, so `en-US` is [`English`][`Self::English`].
+/// Only tags whose primary language is not recognized are kept whole in [`Other`][`Self::Other`].
+///
+/// # Examples
+///
+/// ```rust
+/// use holodex::model::Language;
+///
+/// assert_eq!("en-US".parse::<Language>()?, Language::English);
+/// assert_eq!("zh-TW".parse::<Language>()?, Language::Chinese);
+/// assert_eq!("pt-BR".parse::<Language>()?, Language::Other("pt-BR".to_owned()));
+///
+/// let languages: Vec<Language> = serde_json::from_str(r#"["en", "en-GB", "ja-JP"]"#)?;
+/// assert_eq!(languages, [Language::English, Language::English, Language::Japanese]);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
 pub enum Language {
     /// Include all languages.
     All,
@@ -498,6 +917,35 @@ pub enum Language {
     Other(String),
 }
 
+impl Language {
+    #[inline]
+    #[must_use]
+    /// All languages with a dedicated variant, excluding [`Other`][`Self::Other`].
+    ///
+    /// This includes [`All`][`Self::All`], which matches every language when used in a filter.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use holodex::model::Language;
+    ///
+    /// let codes: Vec<_> = Language::known().iter().map(ToString::to_string).collect();
+    /// assert_eq!(codes, ["all", "en", "es", "id", "ja", "ko", "ru", "zh"]);
+    /// ```
+    pub const fn known() -> &'static [Self] {
+        &[
+            Self::All,
+            Self::English,
+            Self::Spanish,
+            Self::Indonesian,
+            Self::Japanese,
+            Self::Korean,
+            Self::Russian,
+            Self::Chinese,
+        ]
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 /// What order items should be returned in, ascending or descending.
 pub enum Order {
@@ -522,19 +970,69 @@ impl Display for Order {
 #[allow(clippy::use_self)]
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 /// Which organization the VTuber(s) are a part of.
+///
+/// # Examples
+///
+/// Organisations not covered by a variant are sent to the API verbatim.
+/// ```rust
+/// use holodex::model::{
+///     builders::{ChannelFilterBuilder, VideoFilterBuilder, VideoSearchBuilder},
+///     Organisation,
+/// };
+///
+/// let org = Organisation::Other("VSpo".to_owned());
+///
+/// let channel_filter = ChannelFilterBuilder::new().organisation(org.clone()).build()?;
+/// let query = serde_urlencoded::to_string(&channel_filter).unwrap();
+/// assert!(query.split('&').any(|pair| pair == "org=VSpo"));
+///
+/// let video_filter = VideoFilterBuilder::new().organisation(org.clone()).build();
+/// let query = serde_urlencoded::to_string(&video_filter).unwrap();
+/// assert!(query.split('&').any(|pair| pair == "org=VSpo"));
+///
+/// let search = VideoSearchBuilder::new().organisations(&[org]).build();
+/// let body = serde_json::to_value(&search).unwrap();
+/// assert_eq!(body["org"], serde_json::json!(["VSpo"]));
+/// # Ok::<(), holodex::errors::Error>(())
+/// ```
 pub enum Organisation {
-    /// VTubers from [Hololive Production](https://en.hololive.tv/)
+    /// `VTubers` from [Hololive Production](https://en.hololive.tv/)
     Hololive,
-    /// VTubers from [Nijisanji](https://www.nijisanji.jp/en/)
+    /// `VTubers` from [Nijisanji](https://www.nijisanji.jp/en/)
     Nijisanji,
-    /// VTubers from [VOMS Project](https://voms.net/)
+    /// `VTubers` from [VOMS Project](https://voms.net/)
     VOMS,
-    /// VTubers not part of any organization.
+    /// `VTubers` not part of any organization.
     Independents,
     /// Organization not covered by other variants, please submit a pull request to add them!
     Other(String),
 }
 
+impl Organisation {
+    #[inline]
+    #[must_use]
+    /// All organisations with a dedicated variant, excluding [`Other`][`Self::Other`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use holodex::model::Organisation;
+    ///
+    /// assert!(Organisation::known().contains(&Organisation::Hololive));
+    /// assert!(Organisation::known()
+    ///     .iter()
+    ///     .all(|org| !matches!(org, Organisation::Other(_))));
+    /// ```
+    pub const fn known() -> &'static [Self] {
+        &[
+            Self::Hololive,
+            Self::Nijisanji,
+            Self::VOMS,
+            Self::Independents,
+        ]
+    }
+}
+
 #[non_exhaustive]
 #[derive(Serialize, Deserialize, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[serde(rename_all = "snake_case")]
@@ -599,6 +1097,171 @@ impl Display for VideoSortingCriteria {
     }
 }
 
+impl VideoSortingCriteria {
+    #[must_use]
+    /// Compare two videos by this criteria in ascending order, the same way the API sorts them.
+    ///
+    /// Missing values are ordered after all present values, so they end up last when sorting
+    /// in ascending order and first when sorting in descending order.
+    /// [`Type`][`Self::Type`] and [`Status`][`Self::Status`] are compared by their names.
+    ///
+    /// [`AvailableAt`][`Self::AvailableAt`] and [`SongCount`][`Self::SongCount`] use the values
+    /// included in the video, which the API computes from data that is not always returned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::cmp::Ordering;
+    /// use holodex::model::{Video, VideoSortingCriteria};
+    ///
+    /// let videos: Vec<Video> = serde_json::from_str(r#"[
+    ///     { "id": "IhiievWaZMI", "title": "B", "type": "stream", "status": "past",
+    ///       "available_at": "2021-07-01T12:00:00Z", "channel_id": "UCS9uQI-jC3DE0L4IpXyvr6w" },
+    ///     { "id": "v6o7LBrQs-I", "title": "A", "type": "stream", "status": "live",
+    ///       "available_at": "2021-07-02T12:00:00Z", "channel_id": "UCS9uQI-jC3DE0L4IpXyvr6w",
+    ///       "live_viewers": 1000 }
+    /// ]"#)?;
+    ///
+    /// assert_eq!(VideoSortingCriteria::Title.compare(&videos[0], &videos[1]), Ordering::Greater);
+    /// assert_eq!(VideoSortingCriteria::AvailableAt.compare(&videos[0], &videos[1]), Ordering::Less);
+    /// assert_eq!(VideoSortingCriteria::Status.compare(&videos[0], &videos[1]), Ordering::Greater);
+    ///
+    /// // The first video has no viewer count, so it is ordered last.
+    /// assert_eq!(VideoSortingCriteria::LiveViewers.compare(&videos[0], &videos[1]), Ordering::Greater);
+    /// # Ok::<(), serde_json::Error>(())
+    /// ```
+    pub fn compare(&self, a: &Video, b: &Video) -> Ordering {
+        /// Order missing values last, like the API does.
+        fn nulls_last<T: Ord>(a: Option<T>, b: Option<T>) -> Ordering {
+            match (a, b) {
+                (Some(a), Some(b)) => a.cmp(&b),
+                (Some(_), None) => Ordering::Less,
+                (None, Some(_)) => Ordering::Greater,
+                (None, None) => Ordering::Equal,
+            }
+        }
+
+        match *self {
+            VideoSortingCriteria::Id => a.id.cmp(&b.id),
+            VideoSortingCriteria::Title => a.title.cmp(&b.title),
+            VideoSortingCriteria::Type => a.video_type.to_string().cmp(&b.video_type.to_string()),
+            VideoSortingCriteria::Topics => nulls_last(a.topic.as_ref(), b.topic.as_ref()),
+            VideoSortingCriteria::PublishedAt => nulls_last(a.published_at, b.published_at),
+            VideoSortingCriteria::AvailableAt => a.available_at.cmp(&b.available_at),
+            VideoSortingCriteria::Duration => nulls_last(a.duration, b.duration),
+            VideoSortingCriteria::Status => a.status.to_string().cmp(&b.status.to_string()),
+            VideoSortingCriteria::StartScheduled => {
+                nulls_last(a.live_info.start_scheduled, b.live_info.start_scheduled)
+            }
+            VideoSortingCriteria::StartActual => {
+                nulls_last(a.live_info.start_actual, b.live_info.start_actual)
+            }
+            VideoSortingCriteria::EndActual => {
+                nulls_last(a.live_info.end_actual, b.live_info.end_actual)
+            }
+            VideoSortingCriteria::LiveViewers => {
+                nulls_last(a.live_info.live_viewers, b.live_info.live_viewers)
+            }
+            VideoSortingCriteria::Description => {
+                nulls_last(a.description.as_ref(), b.description.as_ref())
+            }
+            VideoSortingCriteria::SongCount => nulls_last(a.song_count, b.song_count),
+            VideoSortingCriteria::ChannelId => a.channel_id().cmp(b.channel_id()),
+        }
+    }
+
+    /// Get a function comparing two videos by this criteria in the given order, the same way the API sorts them.
+    ///
+    /// This is [`compare`][`Self::compare`], reversed for [`Descending`][`Order::Descending`] order.
+    /// It can be passed to sorting and merging routines, for example to merge several result lists
+    /// fetched with the same sorting into one list with that sorting.
+    ///
+    /// # Examples
+    ///
+    /// Merge two lists that are each sorted by newest first.
+    /// ```rust
+    /// use holodex::model::{Order, Video, VideoSortingCriteria};
+    ///
+    /// let hololive: Vec<Video> = serde_json::from_str(r#"[
+    ///     { "id": "IhiievWaZMI", "title": "Third", "type": "stream", "status": "past",
+    ///       "available_at": "2021-07-03T12:00:00Z", "channel_id": "UCS9uQI-jC3DE0L4IpXyvr6w" },
+    ///     { "id": "v6o7LBrQs-I", "title": "First", "type": "stream", "status": "past",
+    ///       "available_at": "2021-07-01T12:00:00Z", "channel_id": "UCS9uQI-jC3DE0L4IpXyvr6w" }
+    /// ]"#)?;
+    /// let nijisanji: Vec<Video> = serde_json::from_str(r#"[
+    ///     { "id": "kpgG2jIw6kQ", "title": "Second", "type": "stream", "status": "past",
+    ///       "available_at": "2021-07-02T12:00:00Z", "channel_id": "UCS9uQI-jC3DE0L4IpXyvr6w" }
+    /// ]"#)?;
+    ///
+    /// let newest_first = VideoSortingCriteria::AvailableAt.comparator(Order::Descending);
+    ///
+    /// let mut merged = Vec::new();
+    /// let (mut left, mut right) = (hololive.into_iter().peekable(), nijisanji.into_iter().peekable());
+    ///
+    /// while let (Some(a), Some(b)) = (left.peek(), right.peek()) {
+    ///     let next = if newest_first(a, b).is_le() { left.next() } else { right.next() };
+    ///     merged.extend(next);
+    /// }
+    /// merged.extend(left.chain(right));
+    ///
+    /// let titles: Vec<_> = merged.iter().map(|video| video.title.as_str()).collect();
+    /// assert_eq!(titles, ["Third", "Second", "First"]);
+    /// # Ok::<(), serde_json::Error>(())
+    /// ```
+    pub fn comparator(self, order: Order) -> impl Fn(&Video, &Video) -> Ordering {
+        move |a, b| match order {
+            Order::Ascending => self.compare(a, b),
+            Order::Descending => self.compare(b, a),
+        }
+    }
+}
+
+/// The current time, read from the system clock.
+fn now() -> DateTime<Utc> {
+    std::time::SystemTime::now().into()
+}
+
+/// Sort videos in-place by the given criteria and order, the same way the API sorts them.
+///
+/// This is useful to get a single coherent order after combining the results of several queries.
+/// The sort is stable, so videos that compare equal keep their relative order.
+/// See [`VideoSortingCriteria::compare`] for how each criteria is compared.
+///
+/// # Examples
+///
+/// ```rust
+/// use holodex::model::{sort_videos, Order, Video, VideoSortingCriteria};
+///
+/// let mut videos: Vec<Video> = serde_json::from_str(r#"[
+///     { "id": "IhiievWaZMI", "title": "Short", "type": "stream", "status": "past",
+///       "available_at": "2021-07-01T12:00:00Z", "channel_id": "UCS9uQI-jC3DE0L4IpXyvr6w",
+///       "duration": 600 },
+///     { "id": "v6o7LBrQs-I", "title": "Unknown", "type": "stream", "status": "upcoming",
+///       "available_at": "2021-07-03T12:00:00Z", "channel_id": "UCS9uQI-jC3DE0L4IpXyvr6w" },
+///     { "id": "kpgG2jIw6kQ", "title": "Long", "type": "stream", "status": "past",
+///       "available_at": "2021-07-02T12:00:00Z", "channel_id": "UCS9uQI-jC3DE0L4IpXyvr6w",
+///       "duration": 7200 }
+/// ]"#)?;
+///
+/// let titles = |videos: &[Video]| videos.iter().map(|v| v.title.clone()).collect::<Vec<_>>();
+///
+/// sort_videos(&mut videos, VideoSortingCriteria::Duration, Order::Descending);
+/// assert_eq!(titles(&videos), ["Unknown", "Long", "Short"]);
+///
+/// sort_videos(&mut videos, VideoSortingCriteria::Duration, Order::Ascending);
+/// assert_eq!(titles(&videos), ["Short", "Long", "Unknown"]);
+///
+/// sort_videos(&mut videos, VideoSortingCriteria::AvailableAt, Order::Descending);
+/// assert_eq!(titles(&videos), ["Unknown", "Long", "Short"]);
+///
+/// sort_videos(&mut videos, VideoSortingCriteria::Title, Order::Ascending);
+/// assert_eq!(titles(&videos), ["Long", "Short", "Unknown"]);
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+pub fn sort_videos(videos: &mut [Video], by: VideoSortingCriteria, order: Order) {
+    videos.sort_by(by.comparator(order));
+}
+
 #[non_exhaustive]
 #[derive(Serialize, Deserialize, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[serde(rename_all = "snake_case")]
@@ -638,6 +1301,10 @@ pub enum ChannelSortingCriteria {
     /// Sort by when the channel was first published.
     PublishedAt,
     /// Sort by if the channel is marked as [`Channel::inactive`] or not.
+    ///
+    /// Most channels share the same value, and the API does not guarantee any order between those,
+    /// so a channel can move between pages while paging through them.
+    /// Use [`ChannelFilter::exclude_inactive`] to leave out inactive channels instead.
     Inactive,
     /// Sort alphabetically by channel description.
     Description,
@@ -671,11 +1338,28 @@ impl Display for ChannelSortingCriteria {
 #[derive(Serialize, Deserialize, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[serde(rename_all = "snake_case")]
 /// The type of the video.
+///
+/// # Examples
+///
+/// Types added to the API later are parsed as [`Unknown`][`Self::Unknown`],
+/// instead of failing to parse the whole response.
+/// ```rust
+/// use holodex::model::VideoType;
+///
+/// let types: Vec<VideoType> = serde_json::from_str(r#"["stream", "placeholder"]"#)?;
+/// assert_eq!(types, [VideoType::Stream, VideoType::Unknown]);
+/// assert!(serde_json::to_string(&VideoType::Unknown).is_err());
+/// # Ok::<(), serde_json::Error>(())
+/// ```
 pub enum VideoType {
     /// The video is a livestream.
     Stream,
     /// The video is a clip.
     Clip,
+    #[serde(other)]
+    #[serde(skip_serializing)]
+    /// A type not known to this crate. This cannot be used in filters, and fails to serialize.
+    Unknown,
 }
 
 impl Display for VideoType {
@@ -683,6 +1367,7 @@ impl Display for VideoType {
         match *self {
             VideoType::Stream => f.pad("Stream"),
             VideoType::Clip => f.pad("Clip"),
+            VideoType::Unknown => f.pad("Unknown"),
         }
     }
 }
@@ -715,6 +1400,19 @@ impl Display for ChannelVideoType {
 #[derive(Serialize, Deserialize, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[serde(rename_all = "snake_case")]
 /// The status of the [`Video`].
+///
+/// # Examples
+///
+/// Statuses added to the API later are parsed as [`Unknown`][`Self::Unknown`],
+/// instead of failing to parse the whole response.
+/// ```rust
+/// use holodex::model::VideoStatus;
+///
+/// let statuses: Vec<VideoStatus> = serde_json::from_str(r#"["live", "archived"]"#)?;
+/// assert_eq!(statuses, [VideoStatus::Live, VideoStatus::Unknown]);
+/// assert!(serde_json::to_string(&VideoStatus::Unknown).is_err());
+/// # Ok::<(), serde_json::Error>(())
+/// ```
 pub enum VideoStatus {
     /// The video hasn't been properly indexed yet.
     New,
@@ -726,6 +1424,34 @@ pub enum VideoStatus {
     Past,
     /// The video used to exist, but is no longer available.
     Missing,
+    #[serde(other)]
+    #[serde(skip_serializing)]
+    /// A status not known to this crate. This cannot be used in filters, and fails to serialize.
+    Unknown,
+}
+
+impl VideoStatus {
+    #[inline]
+    #[must_use]
+    /// The statuses of videos that are available, or scheduled to be:
+    /// [`Upcoming`][`Self::Upcoming`], [`Live`][`Self::Live`] and [`Past`][`Self::Past`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use holodex::model::{VideoFilter, VideoStatus};
+    ///
+    /// let filter = VideoFilter {
+    ///     status: VideoStatus::active_statuses().to_vec(),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// assert!(!filter.status.contains(&VideoStatus::Missing));
+    /// assert!(!filter.status.contains(&VideoStatus::New));
+    /// ```
+    pub const fn active_statuses() -> &'static [Self] {
+        &[Self::Upcoming, Self::Live, Self::Past]
+    }
 }
 
 impl Display for VideoStatus {
@@ -736,6 +1462,7 @@ impl Display for VideoStatus {
             VideoStatus::Live => f.pad("live"),
             VideoStatus::Past => f.pad("past"),
             VideoStatus::Missing => f.pad("missing"),
+            VideoStatus::Unknown => f.pad("unknown"),
         }
     }
 }
@@ -759,15 +1486,55 @@ impl From<PaginatedTotal> for u32 {
     }
 }
 
-#[derive(Deserialize, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[serde(untagged)]
 /// A paginated result.
+///
+/// A [`Page`][`Self::Page`] can contain no items even though its `total` is non-zero,
+/// if the query was offset past the last match. Code paginating manually should stop
+/// on an empty page rather than relying on `total` alone.
+///
+/// # Examples
+///
+/// ```rust
+/// use holodex::model::PaginatedResult;
+///
+/// let past_the_end: PaginatedResult<u32> = serde_json::from_str(r#"{ "total": 100, "items": [] }"#)?;
+/// assert!(past_the_end.is_empty());
+/// assert_eq!(past_the_end.get(0), None);
+/// assert_eq!(past_the_end.into_iter().count(), 0);
+///
+/// let page: PaginatedResult<u32> = serde_json::from_str(r#"{ "total": "3", "items": [1, 2, 3] }"#)?;
+/// assert_eq!(page[1], 2);
+/// assert_eq!(page.into_iter().collect::<Vec<_>>(), [1, 2, 3]);
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+///
+/// Results serialize back into the shape they were parsed from, so they can be cached and reloaded.
+///
+/// ```rust
+/// use holodex::model::PaginatedResult;
+///
+/// let page: PaginatedResult<u32> = serde_json::from_str(r#"{ "total": "3", "items": [1, 2, 3] }"#)?;
+/// let json = serde_json::to_string(&page)?;
+/// assert_eq!(json, r#"{"total":"3","items":[1,2,3]}"#);
+/// assert_eq!(serde_json::from_str::<PaginatedResult<u32>>(&json)?, page);
+///
+/// let items: PaginatedResult<u32> = serde_json::from_str("[1, 2, 3]")?;
+/// let json = serde_json::to_string(&items)?;
+/// assert_eq!(json, "[1,2,3]");
+/// assert_eq!(serde_json::from_str::<PaginatedResult<u32>>(&json)?, items);
+/// # Ok::<(), serde_json::Error>(())
+/// ```
 pub enum PaginatedResult<T> {
     /// All items that matched the criteria.
     Items(#[serde(default = "Default::default")] Vec<T>),
     /// A paginated result.
     Page {
         /// How many items in total matched the criteria.
+        ///
+        /// This is not necessarily the same as how many items have been returned so far,
+        /// see the type-level documentation.
         total: PaginatedTotal,
         #[serde(default = "Default::default")]
         /// `limit` items, offset by `offset`.
@@ -785,6 +1552,34 @@ impl<T> PaginatedResult<T> {
         }
     }
 
+    #[must_use]
+    #[inline]
+    /// Get the first item from the response, or `None` if it is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use holodex::model::PaginatedResult;
+    ///
+    /// let result: PaginatedResult<u32> = serde_json::from_str(r#"{ "total": 3, "items": [1, 2, 3] }"#)?;
+    /// assert_eq!(result.first(), Some(&1));
+    /// assert_eq!(result.last(), Some(&3));
+    ///
+    /// let empty: PaginatedResult<u32> = serde_json::from_str("[]")?;
+    /// assert_eq!(empty.first(), None);
+    /// # Ok::<(), serde_json::Error>(())
+    /// ```
+    pub fn first(&self) -> Option<&T> {
+        self.items().first()
+    }
+
+    #[must_use]
+    #[inline]
+    /// Get the last item from the response, or `None` if it is empty.
+    pub fn last(&self) -> Option<&T> {
+        self.items().last()
+    }
+
     #[must_use]
     #[inline]
     #[allow(clippy::missing_const_for_fn)]
@@ -794,6 +1589,139 @@ impl<T> PaginatedResult<T> {
             PaginatedResult::Items(items) | PaginatedResult::Page { items, .. } => items,
         }
     }
+
+    #[must_use]
+    #[inline]
+    /// Returns `true` if the query matched nothing at all.
+    ///
+    /// This differs from [`is_empty`][`slice::is_empty`], which is also `true` when a paginated
+    /// query has been offset past the last match.
+    /// A [`Page`][`Self::Page`] only matched nothing if its `total` is zero.
+    ///
+    /// An [`Items`][`Self::Items`] result carries no total, so it is assumed to contain every match,
+    /// and matched nothing if it is empty. This does not hold if the endpoint silently
+    /// limited the result, so prefer paginated queries when the distinction matters.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use holodex::model::PaginatedResult;
+    ///
+    /// let no_matches: PaginatedResult<u32> = serde_json::from_str(r#"{ "total": 0, "items": [] }"#)?;
+    /// assert!(no_matches.matched_nothing());
+    ///
+    /// let past_the_end: PaginatedResult<u32> = serde_json::from_str(r#"{ "total": "12", "items": [] }"#)?;
+    /// assert!(past_the_end.is_empty());
+    /// assert!(!past_the_end.matched_nothing());
+    ///
+    /// let unpaginated: PaginatedResult<u32> = serde_json::from_str("[]")?;
+    /// assert!(unpaginated.matched_nothing());
+    /// # Ok::<(), serde_json::Error>(())
+    /// ```
+    pub fn matched_nothing(&self) -> bool {
+        match self {
+            PaginatedResult::Items(items) => items.is_empty(),
+            PaginatedResult::Page { total, .. } => u32::from(*total) == 0,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns `true` if another page should be requested after this one,
+    /// given that `received` items have been received in total, including the items of this page.
+    ///
+    /// Only a non-empty [`Page`][`Self::Page`] can be followed by more items, if fewer than its `total` have been received.
+    /// Bare [`Items`][`Self::Items`] are never followed by more, since some endpoints return every match at once
+    /// instead of a page, even when asked to paginate.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use holodex::model::PaginatedResult;
+    ///
+    /// let first: PaginatedResult<u32> = serde_json::from_str(r#"{ "total": "5", "items": [1, 2, 3] }"#)?;
+    /// assert!(first.has_more(3));
+    ///
+    /// let last: PaginatedResult<u32> = serde_json::from_str(r#"{ "total": "5", "items": [4, 5] }"#)?;
+    /// assert!(!last.has_more(5));
+    ///
+    /// let past_the_end: PaginatedResult<u32> = serde_json::from_str(r#"{ "total": "12", "items": [] }"#)?;
+    /// assert!(!past_the_end.has_more(5));
+    ///
+    /// let unpaginated: PaginatedResult<u32> = serde_json::from_str("[1, 2, 3, 4, 5]")?;
+    /// assert!(!unpaginated.has_more(5));
+    /// # Ok::<(), serde_json::Error>(())
+    /// ```
+    pub fn has_more(&self, received: usize) -> bool {
+        match self {
+            PaginatedResult::Items(_) => false,
+            PaginatedResult::Page { total, items } => {
+                !items.is_empty() && received < u32::from(*total) as usize
+            }
+        }
+    }
+
+    #[inline]
+    #[allow(clippy::missing_const_for_fn)]
+    fn items_mut(&mut self) -> &mut Vec<T> {
+        match self {
+            PaginatedResult::Items(items) | PaginatedResult::Page { items, .. } => items,
+        }
+    }
+}
+
+#[allow(clippy::multiple_inherent_impl)]
+impl PaginatedResult<Video> {
+    #[inline]
+    /// Sort the videos in-place by [`Video::available_at`], from earliest to latest.
+    ///
+    /// The sort is stable, so videos available at the same time keep their relative order.
+    pub fn sort_by_available_at(&mut self) {
+        self.items_mut().sort_by(Video::cmp_available_at);
+    }
+
+    #[must_use]
+    #[inline]
+    /// Consume the result, returning the videos sorted by [`Video::available_at`], from earliest to latest.
+    ///
+    /// This is the same as [`sort_by_available_at`][`Self::sort_by_available_at`],
+    /// but usable without a mutable binding.
+    pub fn sorted_by_available_at(mut self) -> Vec<Video> {
+        self.sort_by_available_at();
+        self.into_items()
+    }
+
+    #[inline]
+    /// Remove any videos whose ID already appeared earlier in the result, keeping the first occurrence.
+    ///
+    /// The reported total of a paginated result is left as-is.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use holodex::model::{PaginatedResult, Video};
+    ///
+    /// let mut videos: PaginatedResult<Video> = serde_json::from_str(r#"[
+    ///     { "id": "IhiievWaZMI", "title": "Live", "type": "stream", "status": "live",
+    ///       "available_at": "2021-07-01T12:00:00Z", "channel_id": "UCS9uQI-jC3DE0L4IpXyvr6w" },
+    ///     { "id": "v6o7LBrQs-I", "title": "Upcoming", "type": "stream", "status": "upcoming",
+    ///       "available_at": "2021-07-01T14:00:00Z", "channel_id": "UCS9uQI-jC3DE0L4IpXyvr6w" },
+    ///     { "id": "IhiievWaZMI", "title": "Live again", "type": "stream", "status": "live",
+    ///       "available_at": "2021-07-01T12:00:00Z", "channel_id": "UCS9uQI-jC3DE0L4IpXyvr6w" }
+    /// ]"#)?;
+    ///
+    /// videos.dedup_by_id();
+    ///
+    /// assert_eq!(videos.len(), 2);
+    /// assert_eq!(videos[0].title, "Live");
+    /// assert_eq!(videos[1].title, "Upcoming");
+    /// # Ok::<(), serde_json::Error>(())
+    /// ```
+    pub fn dedup_by_id(&mut self) {
+        let mut seen = HashSet::new();
+        self.items_mut()
+            .retain(|video| seen.insert(video.id.clone()));
+    }
 }
 
 impl<T> Deref for PaginatedResult<T> {
@@ -805,6 +1733,56 @@ impl<T> Deref for PaginatedResult<T> {
     }
 }
 
+/// Allows passing the result to generic code taking [`AsRef<[T]>`][`AsRef`].
+///
+/// # Examples
+///
+/// ```rust
+/// use holodex::model::PaginatedResult;
+///
+/// fn sum(values: impl AsRef<[u32]>) -> u32 {
+///     values.as_ref().iter().sum()
+/// }
+///
+/// let result: PaginatedResult<u32> = serde_json::from_str("[1, 2, 3]")?;
+/// assert_eq!(sum(&result), 6);
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+impl<T> AsRef<[T]> for PaginatedResult<T> {
+    #[inline]
+    fn as_ref(&self) -> &[T] {
+        self.items()
+    }
+}
+
+/// Summarizes the result by how many items it contains, and the total for pages, without printing the items.
+///
+/// # Examples
+///
+/// ```rust
+/// use holodex::model::PaginatedResult;
+///
+/// let page: PaginatedResult<u32> = serde_json::from_str(r#"{ "total": "4821", "items": [1, 2, 3] }"#)?;
+/// assert_eq!(page.to_string(), "PaginatedResult(3 items, total: 4821)");
+///
+/// let items: PaginatedResult<u32> = serde_json::from_str("[1, 2, 3]")?;
+/// assert_eq!(items.to_string(), "PaginatedResult(3 items)");
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+impl<T> Display for PaginatedResult<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PaginatedResult::Items(items) => write!(f, "PaginatedResult({} items)", items.len()),
+            PaginatedResult::Page { total, items } => write!(
+                f,
+                "PaginatedResult({} items, total: {})",
+                items.len(),
+                u32::from(*total)
+            ),
+        }
+    }
+}
+
 impl<T> IntoIterator for PaginatedResult<T> {
     type Item = T;
     type IntoIter = std::vec::IntoIter<Self::Item>;
@@ -826,54 +1804,444 @@ impl<T> From<PaginatedResult<T>> for Vec<T> {
     }
 }
 
-#[derive(Deserialize, Debug, Clone, Eq, PartialOrd, Ord)]
-/// A video, that can be either a stream, premiere, or clip.
-pub struct Video {
-    /// The ID of the video.
-    pub id: VideoId,
-    /// The title of the video.
-    pub title: String,
-    #[serde(rename = "type")]
-    /// The type of the video.
+#[derive(Debug)]
+/// A page of results where each item was parsed on its own,
+/// so that an item that fails to parse does not cause the whole page to fail.
+///
+/// Returned by [`Client::videos_lenient`][`crate::Client::videos_lenient`],
+/// and can be collected from any iterator of JSON values.
+///
+/// # Examples
+///
+/// ```rust
+/// use holodex::model::{LenientPage, Video};
+///
+/// let values: Vec<serde_json::Value> = serde_json::from_str(r#"[
+///     { "id": "IhiievWaZMI", "title": "Good", "type": "stream", "status": "past",
+///       "available_at": "2021-07-01T12:00:00Z", "channel_id": "UCS9uQI-jC3DE0L4IpXyvr6w" },
+///     { "id": "v6o7LBrQs-I", "title": "Missing its channel", "type": "stream", "status": "past",
+///       "available_at": "2021-07-01T12:00:00Z" },
+///     { "id": "kpgG2jIw6kQ", "title": "Also good", "type": "clip", "status": "past",
+///       "available_at": "2021-07-02T12:00:00Z", "channel_id": "UCS9uQI-jC3DE0L4IpXyvr6w" }
+/// ]"#)?;
+///
+/// let page: LenientPage<Video> = values.into_iter().collect();
+///
+/// assert_eq!(page.items.len(), 2);
+/// assert_eq!(page.errors.len(), 1);
+/// assert_eq!(page.errors[0].0, 1);
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+pub struct LenientPage<T> {
+    /// The items that were parsed successfully, in the order they were received.
+    pub items: Vec<T>,
+    /// The items that could not be parsed, as their position in the page along with why parsing failed.
+    pub errors: Vec<(usize, ParseError)>,
+}
+
+impl<T> LenientPage<T> {
+    #[inline]
+    #[must_use]
+    /// Returns `true` if every item was parsed successfully.
+    pub const fn is_complete(&self) -> bool {
+        self.errors.is_empty()
+    }
+}
+
+impl<T> FromIterator<serde_json::Value> for LenientPage<T>
+where
+    T: DeserializeOwned,
+{
+    fn from_iter<I: IntoIterator<Item = serde_json::Value>>(values: I) -> Self {
+        let mut page = Self {
+            items: Vec::new(),
+            errors: Vec::new(),
+        };
+
+        for (index, value) in values.into_iter().enumerate() {
+            match serde_json::from_value(value) {
+                Ok(item) => page.items.push(item),
+                Err(e) => page.errors.push((index, ParseError::ValueParseError(e))),
+            }
+        }
+
+        page
+    }
+}
+
+#[non_exhaustive]
+#[derive(Deserialize, Debug, Clone, Eq, PartialOrd, Ord)]
+#[serde(try_from = "serializers::VideoDe")]
+/// A video, that can be either a stream, premiere, or clip.
+pub struct Video {
+    /// The ID of the video.
+    pub id: VideoId,
+    /// The title of the video.
+    pub title: String,
+    /// The type of the video.
     pub video_type: VideoType,
-    #[serde(default)]
-    #[serde(rename = "topic_id")]
     /// The main topic the video is about.
     /// Videos of type `clip` cannot have a topic.
     pub topic: Option<String>,
-    #[serde(default)]
     /// The date the video was first published.
     pub published_at: Option<DateTime<Utc>>,
     /// Takes on the first `Some` value of [`live_info.end_actual`][`VideoLiveInfo::end_actual`],
     /// [`live_info.start_actual`][`VideoLiveInfo::start_actual`],
     /// [`live_info.start_scheduled`][VideoLiveInfo::start_scheduled`], or
     /// [`published_at`](#structfield.published_at).
+    ///
+    /// If the API omits this field, it is derived from the fields above in the same order.
     pub available_at: DateTime<Utc>,
-    #[serde(with = "serde_with::As::<Option<DurationSeconds<i64>>>")]
-    #[serde(default)]
     /// The length of the video in seconds.
     pub duration: Option<Duration>,
     /// The status of the video.
     pub status: VideoStatus,
-    #[serde(flatten)]
     /// Live stream information regarding the video, if it is a stream.
     ///
     /// Included when [`VideoFilter::include`] includes [`ExtraVideoInfo::LiveInfo`].
     pub live_info: VideoLiveInfo,
-    #[serde(default)]
     /// The description of the video.
     ///
     /// Included when [`VideoFilter::include`] includes [`ExtraVideoInfo::Description`].
     pub description: Option<String>,
-    #[serde(rename = "songcount")]
-    #[serde(default)]
     /// How many songs have been sung in the video, if any.
     pub song_count: Option<u32>,
-    #[serde(alias = "channel_id")]
     /// The channel the video was uploaded by.
     pub channel: VideoChannel,
+    /// Any channels that were mentioned in the video's description.
+    ///
+    /// Included when [`VideoFilter::include`] includes [`ExtraVideoInfo::Mentions`].
+    /// When the video is part of a [`VideoFull`], the mentions are in [`VideoFull::mentions`] instead,
+    /// and this is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use holodex::model::Video;
+    ///
+    /// let video: Video = serde_json::from_str(r#"{
+    ///     "id": "IhiievWaZMI", "title": "Collab", "type": "stream", "status": "past",
+    ///     "available_at": "2021-07-01T12:00:00Z", "channel_id": "UCS9uQI-jC3DE0L4IpXyvr6w",
+    ///     "mentions": [{
+    ///         "id": "UCL_qhgtOy0dy1Agp8vkySQg", "name": "Mori Calliope Ch. hololive-EN",
+    ///         "type": "vtuber", "photo": "https://example.com/calli.png"
+    ///     }]
+    /// }"#)?;
+    ///
+    /// assert_eq!(video.mentions.len(), 1);
+    /// assert_eq!(video.mentions[0].id, "UCL_qhgtOy0dy1Agp8vkySQg");
+    /// # Ok::<(), serde_json::Error>(())
+    /// ```
+    pub mentions: Vec<ChannelMin>,
+    #[cfg(feature = "extra-fields")]
+    /// Any fields returned by the API that are not modeled by this struct.
+    pub extra: ExtraFields,
+}
+
+impl Video {
+    const MEMBERS_ONLY_TOPIC: &'static str = "membersonly";
+
+    #[must_use]
+    /// Create a video from its required values, to fill in with the `with_*` methods.
+    ///
+    /// Every optional value starts out unset, which is mostly useful for building videos in tests,
+    /// since videos can otherwise only be created by parsing them.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::{Duration, TimeZone, Utc};
+    /// use holodex::model::{Video, VideoLiveInfo, VideoStatus, VideoType};
+    ///
+    /// let start = Utc.with_ymd_and_hms(2021, 7, 1, 12, 0, 0).unwrap();
+    /// let video = Video::new(
+    ///     "IhiievWaZMI".parse()?,
+    ///     "Graduation",
+    ///     VideoType::Stream,
+    ///     VideoStatus::Past,
+    ///     start,
+    ///     "UCS9uQI-jC3DE0L4IpXyvr6w".parse::<holodex::model::id::ChannelId>()?,
+    /// )
+    /// .with_topic("singing")
+    /// .with_duration(Duration::hours(2))
+    /// .with_live_info(VideoLiveInfo::new().with_start_actual(start));
+    ///
+    /// assert_eq!(video.channel_id(), "UCS9uQI-jC3DE0L4IpXyvr6w");
+    /// assert_eq!(video.topic.as_deref(), Some("singing"));
+    /// assert_eq!(video.live_info.start_actual, Some(start));
+    /// assert_eq!(video.description, None);
+    /// # Ok::<(), holodex::errors::Error>(())
+    /// ```
+    pub fn new(
+        id: VideoId,
+        title: &str,
+        video_type: VideoType,
+        status: VideoStatus,
+        available_at: DateTime<Utc>,
+        channel: impl Into<VideoChannel>,
+    ) -> Self {
+        Self {
+            id,
+            title: title.to_owned(),
+            video_type,
+            topic: None,
+            published_at: None,
+            available_at,
+            duration: None,
+            status,
+            live_info: VideoLiveInfo::new(),
+            description: None,
+            song_count: None,
+            channel: channel.into(),
+            mentions: Vec::new(),
+            #[cfg(feature = "extra-fields")]
+            extra: ExtraFields::default(),
+        }
+    }
+
+    #[must_use]
+    /// Set the main topic the video is about.
+    pub fn with_topic(mut self, topic: &str) -> Self {
+        self.topic = Some(topic.to_owned());
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    /// Set when the video was first published.
+    pub const fn with_published_at(mut self, published_at: DateTime<Utc>) -> Self {
+        self.published_at = Some(published_at);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    /// Set the length of the video.
+    pub const fn with_duration(mut self, duration: Duration) -> Self {
+        self.duration = Some(duration);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    /// Set the livestream metadata of the video.
+    pub const fn with_live_info(mut self, live_info: VideoLiveInfo) -> Self {
+        self.live_info = live_info;
+        self
+    }
+
+    #[must_use]
+    /// Set the description of the video.
+    pub fn with_description(mut self, description: &str) -> Self {
+        self.description = Some(description.to_owned());
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    /// Set how many songs were sung in the video.
+    pub const fn with_song_count(mut self, song_count: u32) -> Self {
+        self.song_count = Some(song_count);
+        self
+    }
+
+    #[must_use]
+    /// Set the channels mentioned in the video's description.
+    pub fn with_mentions(mut self, mentions: Vec<ChannelMin>) -> Self {
+        self.mentions = mentions;
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the ID of the channel that uploaded the video.
+    pub const fn channel_id(&self) -> &ChannelId {
+        self.channel.id()
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the name of the channel that uploaded the video,
+    /// if the API included channel metadata and not just the channel ID.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use holodex::model::Video;
+    ///
+    /// let video: Video = serde_json::from_str(r#"{
+    ///     "id": "IhiievWaZMI", "title": "Graduation", "type": "stream", "status": "past",
+    ///     "available_at": "2021-07-01T12:00:00Z",
+    ///     "channel": {
+    ///         "id": "UCS9uQI-jC3DE0L4IpXyvr6w", "name": "Coco Ch. 桐生ココ",
+    ///         "type": "vtuber", "photo": "https://example.com/coco.png"
+    ///     }
+    /// }"#)?;
+    ///
+    /// assert_eq!(video.channel_id(), "UCS9uQI-jC3DE0L4IpXyvr6w");
+    /// assert_eq!(video.channel_name(), Some("Coco Ch. 桐生ココ"));
+    ///
+    /// let video: Video = serde_json::from_str(r#"{
+    ///     "id": "IhiievWaZMI", "title": "Graduation", "type": "stream", "status": "past",
+    ///     "available_at": "2021-07-01T12:00:00Z", "channel_id": "UCS9uQI-jC3DE0L4IpXyvr6w"
+    /// }"#)?;
+    ///
+    /// assert_eq!(video.channel_name(), None);
+    /// # Ok::<(), serde_json::Error>(())
+    /// ```
+    pub fn channel_name(&self) -> Option<&str> {
+        self.channel.name()
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns how long ago the video was published,
+    /// or `None` if the API did not include [`published_at`][`Self::published_at`].
+    ///
+    /// See [`age_since_published_at`][`Self::age_since_published_at`] for measuring the age at a given time.
+    pub fn age_since_published(&self) -> Option<Duration> {
+        self.age_since_published_at(now())
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns how long the video had been published at `time`,
+    /// or `None` if the API did not include [`published_at`][`Self::published_at`].
+    ///
+    /// The age is negative if `time` is before the video was published.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::{Duration, TimeZone, Utc};
+    /// use holodex::model::Video;
+    ///
+    /// let video: Video = serde_json::from_str(r#"{
+    ///     "id": "IhiievWaZMI", "title": "Graduation", "type": "stream", "status": "past",
+    ///     "available_at": "2021-07-01T12:00:00Z", "published_at": "2021-06-30T12:00:00Z",
+    ///     "channel_id": "UCS9uQI-jC3DE0L4IpXyvr6w"
+    /// }"#)?;
+    /// let time = Utc.with_ymd_and_hms(2021, 7, 1, 18, 0, 0).unwrap();
+    ///
+    /// assert_eq!(video.age_since_published_at(time), Some(Duration::hours(30)));
+    ///
+    /// let video: Video = serde_json::from_str(r#"{
+    ///     "id": "IhiievWaZMI", "title": "Graduation", "type": "stream", "status": "upcoming",
+    ///     "available_at": "2021-07-01T12:00:00Z", "channel_id": "UCS9uQI-jC3DE0L4IpXyvr6w"
+    /// }"#)?;
+    ///
+    /// assert_eq!(video.age_since_published_at(time), None);
+    /// assert_eq!(video.age_since_published(), None);
+    /// # Ok::<(), serde_json::Error>(())
+    /// ```
+    pub fn age_since_published_at(&self, time: DateTime<Utc>) -> Option<Duration> {
+        self.published_at.map(|published_at| time - published_at)
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns `true` if the video is only available to channel members.
+    ///
+    /// Holodex marks members-only streams by tagging them with the `membersonly` topic,
+    /// so this is `false` for videos that have been tagged with another topic.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use holodex::model::Video;
+    ///
+    /// let video: Video = serde_json::from_str(r#"{
+    ///     "id": "IhiievWaZMI", "title": "Members karaoke", "type": "stream", "status": "past",
+    ///     "topic_id": "membersonly", "available_at": "2021-07-01T12:00:00Z",
+    ///     "channel_id": "UCS9uQI-jC3DE0L4IpXyvr6w"
+    /// }"#)?;
+    ///
+    /// assert!(video.is_members_only());
+    /// # Ok::<(), serde_json::Error>(())
+    /// ```
+    pub fn is_members_only(&self) -> bool {
+        self.topic.as_deref() == Some(Self::MEMBERS_ONLY_TOPIC)
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the livestream metadata of the video, or `None` if none of it is present.
+    ///
+    /// Videos always have a [`live_info`][`Self::live_info`], but for clips, or when the API
+    /// did not include it, every field in it is `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use holodex::model::Video;
+    ///
+    /// let stream: Video = serde_json::from_str(r#"{
+    ///     "id": "IhiievWaZMI", "title": "Stream", "type": "stream", "status": "live",
+    ///     "available_at": "2021-07-01T12:00:00Z", "channel_id": "UCS9uQI-jC3DE0L4IpXyvr6w",
+    ///     "start_actual": "2021-07-01T12:01:00Z", "live_viewers": 1000
+    /// }"#)?;
+    /// assert_eq!(stream.live_info_opt().and_then(|info| info.live_viewers), Some(1000));
+    ///
+    /// let clip: Video = serde_json::from_str(r#"{
+    ///     "id": "v6o7LBrQs-I", "title": "Clip", "type": "clip", "status": "past",
+    ///     "available_at": "2021-07-02T12:00:00Z", "channel_id": "UCS9uQI-jC3DE0L4IpXyvr6w"
+    /// }"#)?;
+    /// assert!(clip.live_info.is_empty());
+    /// assert_eq!(clip.live_info_opt(), None);
+    /// # Ok::<(), serde_json::Error>(())
+    /// ```
+    pub const fn live_info_opt(&self) -> Option<&VideoLiveInfo> {
+        if self.live_info.is_empty() {
+            None
+        } else {
+            Some(&self.live_info)
+        }
+    }
+
+    #[inline]
+    fn cmp_available_at(a: &Self, b: &Self) -> Ordering {
+        a.available_at.cmp(&b.available_at)
+    }
 }
 
+/// Videos are compared by every field except [`duration`][`Video::duration`],
+/// which keeps changing while a stream is live, and any extra fields.
+///
+/// The [`Hash`][`std::hash::Hash`] implementation hashes the same fields,
+/// so equal videos always have equal hashes.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::{collections::hash_map::DefaultHasher, hash::{Hash, Hasher}};
+/// use holodex::model::Video;
+///
+/// fn hash(video: &Video) -> u64 {
+///     let mut hasher = DefaultHasher::new();
+///     video.hash(&mut hasher);
+///     hasher.finish()
+/// }
+///
+/// let video: Video = serde_json::from_str(r#"{
+///     "id": "IhiievWaZMI", "title": "Graduation", "type": "stream", "status": "live",
+///     "available_at": "2021-07-01T12:00:00Z", "duration": 3600,
+///     "channel_id": "UCS9uQI-jC3DE0L4IpXyvr6w"
+/// }"#)?;
+///
+/// let mut longer = video.clone();
+/// longer.duration = Some(chrono::Duration::hours(2));
+/// assert_eq!(video, longer);
+/// assert_eq!(hash(&video), hash(&longer));
+///
+/// let mut renamed = video.clone();
+/// renamed.title = "Graduation stream".to_owned();
+/// assert_ne!(video, renamed);
+///
+/// let mut ended = video.clone();
+/// ended.status = holodex::model::VideoStatus::Past;
+/// assert_ne!(video, ended);
+/// # Ok::<(), serde_json::Error>(())
+/// ```
 impl PartialEq for Video {
     fn eq(&self, other: &Self) -> bool {
         self.id == other.id
@@ -887,6 +2255,7 @@ impl PartialEq for Video {
             && self.description == other.description
             && self.song_count == other.song_count
             && self.channel == other.channel
+            && self.mentions == other.mentions
     }
 }
 
@@ -903,11 +2272,34 @@ impl std::hash::Hash for Video {
         self.description.hash(state);
         self.song_count.hash(state);
         self.channel.hash(state);
+        self.mentions.hash(state);
     }
 }
 
 #[derive(Deserialize, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 /// Smaller version of [`Channel`] with less metadata.
+///
+/// Any fields returned by the API that are not modeled here are ignored,
+/// so a full channel object can be parsed as a `ChannelMin` as well.
+///
+/// # Examples
+///
+/// ```rust
+/// use holodex::model::{ChannelMin, Organisation};
+///
+/// let channel: ChannelMin = serde_json::from_str(r#"{
+///     "id": "UCS9uQI-jC3DE0L4IpXyvr6w", "name": "Coco Ch. 桐生ココ", "english_name": "Kiryu Coco",
+///     "type": "vtuber", "photo": "https://example.com/coco.png", "org": "Hololive",
+///     "suborg": "a Hololive", "banner": "https://example.com/banner.png", "twitter": "kiryucoco",
+///     "lang": "ja", "inactive": true, "published_at": "2019-03-23T00:00:00.000Z",
+///     "top_topics": ["asmr"], "subscriber_count": "1500000", "some_future_field": { "nested": [1, 2] }
+/// }"#)?;
+///
+/// assert_eq!(channel.english_name.as_deref(), Some("Kiryu Coco"));
+/// assert_eq!(channel.org, Some(Organisation::Hololive));
+/// assert_eq!(channel.stats.subscriber_count, Some(1_500_000));
+/// # Ok::<(), serde_json::Error>(())
+/// ```
 pub struct ChannelMin {
     /// The ID of the channel.
     pub id: ChannelId,
@@ -930,6 +2322,7 @@ pub struct ChannelMin {
     pub stats: ChannelStats,
 }
 
+#[non_exhaustive]
 #[derive(Deserialize, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 /// A channel that uploads videos and/or streams.
 pub struct Channel {
@@ -983,21 +2376,259 @@ pub struct Channel {
     pub crawled_at: Option<DateTime<Utc>>,
     /// The date the comments posted on videos uploaded by this channel were last indexed.
     pub comments_crawled_at: Option<DateTime<Utc>>,
+
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    /// Any fields returned by the API that are not modeled by this struct.
+    pub extra: ExtraFields,
+}
+
+impl Channel {
+    #[must_use]
+    /// Create a channel from its required values.
+    ///
+    /// Every other value starts out unset or empty, and can be set through the public fields.
+    /// This is mostly useful for building channels in tests, since channels can otherwise only be created by parsing them.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use holodex::model::{Channel, ChannelType, Organisation};
+    ///
+    /// let mut channel = Channel::new("UCS9uQI-jC3DE0L4IpXyvr6w".parse()?, "Coco Ch. 桐生ココ", ChannelType::VTuber);
+    /// channel.org = Some(Organisation::Hololive);
+    /// channel.stats.subscriber_count = Some(1_520_000);
+    ///
+    /// assert!(!channel.inactive);
+    /// assert_eq!(channel.stats.video_count, None);
+    /// # Ok::<(), holodex::errors::Error>(())
+    /// ```
+    pub fn new(id: ChannelId, name: &str, channel_type: ChannelType) -> Self {
+        Self {
+            id,
+            name: name.to_owned(),
+            inactive: false,
+            channel_type,
+            description: None,
+            lang: None,
+            english_name: None,
+            org: None,
+            suborg: None,
+            photo: None,
+            banner: None,
+            twitter: None,
+            stats: ChannelStats::default(),
+            top_topics: Vec::new(),
+            published_at: None,
+            crawled_at: None,
+            comments_crawled_at: None,
+            #[cfg(feature = "extra-fields")]
+            extra: ExtraFields::default(),
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns how long ago the channel was created,
+    /// or `None` if the API did not include [`published_at`][`Self::published_at`].
+    ///
+    /// See [`age_at`][`Self::age_at`] for measuring the age at a given time.
+    pub fn age(&self) -> Option<Duration> {
+        self.age_at(now())
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns how old the channel was at `time`,
+    /// or `None` if the API did not include [`published_at`][`Self::published_at`].
+    ///
+    /// The age is negative if `time` is before the channel was created.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::{Duration, TimeZone, Utc};
+    /// use holodex::model::Channel;
+    ///
+    /// let channel: Channel = serde_json::from_str(r#"{
+    ///     "id": "UCS9uQI-jC3DE0L4IpXyvr6w", "name": "Coco Ch. 桐生ココ", "type": "vtuber",
+    ///     "published_at": "2019-12-27T00:00:00Z"
+    /// }"#)?;
+    /// let time = Utc.with_ymd_and_hms(2020, 1, 6, 0, 0, 0).unwrap();
+    ///
+    /// assert_eq!(channel.age_at(time), Some(Duration::days(10)));
+    ///
+    /// let channel: Channel = serde_json::from_str(r#"{
+    ///     "id": "UCS9uQI-jC3DE0L4IpXyvr6w", "name": "Coco Ch. 桐生ココ", "type": "vtuber"
+    /// }"#)?;
+    ///
+    /// assert_eq!(channel.age_at(time), None);
+    /// assert_eq!(channel.age(), None);
+    /// # Ok::<(), serde_json::Error>(())
+    /// ```
+    pub fn age_at(&self, time: DateTime<Utc>) -> Option<Duration> {
+        self.published_at.map(|published_at| time - published_at)
+    }
+
+    #[must_use]
+    /// Returns the primary language of the channel, inferring it if the API did not provide one.
+    ///
+    /// If [`lang`][`Self::lang`] is missing, the language is inferred from the name of the
+    /// sub-organization the channel belongs to, such as `NIJISANJI EN` or `Indonesia`.
+    /// The inference is conservative, and falls back to [`Language::All`] if no known
+    /// English, Indonesian, Korean or Chinese branch can be recognized.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use holodex::model::{Channel, Language};
+    ///
+    /// let channel: Channel = serde_json::from_str(r#"{
+    ///     "id": "UCoSrY_IQQVpmIRZ9Xf-y93g", "name": "Gawr Gura Ch. hololive-EN", "type": "vtuber",
+    ///     "org": "Hololive", "suborg": "a English (Myth)"
+    /// }"#)?;
+    /// assert_eq!(channel.primary_language(), Language::English);
+    ///
+    /// let channel: Channel = serde_json::from_str(r#"{
+    ///     "id": "UCS9uQI-jC3DE0L4IpXyvr6w", "name": "Coco Ch. 桐生ココ", "type": "vtuber",
+    ///     "org": "Hololive", "suborg": "a Hololive"
+    /// }"#)?;
+    /// assert_eq!(channel.primary_language(), Language::All);
+    /// # Ok::<(), serde_json::Error>(())
+    /// ```
+    pub fn primary_language(&self) -> Language {
+        if let Some(lang) = &self.lang {
+            return lang.clone();
+        }
+
+        let words = self
+            .suborg
+            .as_deref()
+            .unwrap_or_default()
+            .split(|c: char| !c.is_alphanumeric())
+            .map(str::to_lowercase)
+            .collect::<Vec<_>>();
+        let mentions = |names: &[&str]| words.iter().any(|word| names.contains(&word.as_str()));
+
+        if mentions(&["en", "english"]) {
+            Language::English
+        } else if mentions(&["id", "indonesia", "indonesian"]) {
+            Language::Indonesian
+        } else if mentions(&["kr", "korea", "korean"]) {
+            Language::Korean
+        } else if mentions(&["cn", "china", "chinese"]) {
+            Language::Chinese
+        } else {
+            Language::All
+        }
+    }
+}
+
+#[cfg(feature = "extra-fields")]
+#[derive(Deserialize, Debug, Clone, Default)]
+#[serde(transparent)]
+/// Fields returned by the API that are not modeled by the crate yet.
+///
+/// These are ignored when comparing or hashing the struct they are part of,
+/// so two otherwise identical values with different extra fields are considered equal.
+///
+/// # Examples
+///
+/// ```rust
+/// use holodex::model::Channel;
+///
+/// let channel: Channel = serde_json::from_str(r#"{
+///     "id": "UCS9uQI-jC3DE0L4IpXyvr6w",
+///     "name": "Coco Ch. 桐生ココ",
+///     "type": "vtuber",
+///     "some_new_field": [1, 2, 3]
+/// }"#)?;
+///
+/// assert_eq!(channel.extra["some_new_field"], serde_json::json!([1, 2, 3]));
+/// assert!(!channel.extra.contains_key("name"));
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+pub struct ExtraFields(pub serde_json::Map<String, serde_json::Value>);
+
+#[cfg(feature = "extra-fields")]
+impl Deref for ExtraFields {
+    type Target = serde_json::Map<String, serde_json::Value>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[cfg(feature = "extra-fields")]
+impl PartialEq for ExtraFields {
+    #[inline]
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
 }
 
-#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg(feature = "extra-fields")]
+impl Eq for ExtraFields {}
+
+#[cfg(feature = "extra-fields")]
+impl PartialOrd for ExtraFields {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg(feature = "extra-fields")]
+impl Ord for ExtraFields {
+    #[inline]
+    fn cmp(&self, _other: &Self) -> Ordering {
+        Ordering::Equal
+    }
+}
+
+#[cfg(feature = "extra-fields")]
+impl std::hash::Hash for ExtraFields {
+    #[inline]
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
+#[non_exhaustive]
+#[derive(Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 /// Various statistics about a channel.
+///
+/// The API returns these either as numbers or as strings containing numbers, both are accepted.
+///
+/// # Examples
+///
+/// ```rust
+/// use holodex::model::ChannelStats;
+///
+/// let stats: ChannelStats = serde_json::from_str(
+///     r#"{ "video_count": "141", "subscriber_count": 1000, "view_count": "25000" }"#,
+/// )?;
+///
+/// assert_eq!(stats.video_count, Some(141));
+/// assert_eq!(stats.subscriber_count, Some(1000));
+/// assert_eq!(stats.view_count, Some(25000));
+/// assert_eq!(stats.clip_count, None);
+/// # Ok::<(), serde_json::Error>(())
+/// ```
 pub struct ChannelStats {
     #[serde(default)]
+    #[serde(deserialize_with = "serializers::number_or_string")]
     /// The amount of videos the channel has uploaded.
     pub video_count: Option<u32>,
     #[serde(default)]
+    #[serde(deserialize_with = "serializers::number_or_string")]
     /// The amount of subscribers the channel has.
     pub subscriber_count: Option<u32>,
     #[serde(default)]
+    #[serde(deserialize_with = "serializers::number_or_string")]
     /// The amount of views the channel has in total.
     pub view_count: Option<u32>,
     #[serde(default)]
+    #[serde(deserialize_with = "serializers::number_or_string")]
     /// The amount of clips that have been made from videos uploaded by this channel.
     pub clip_count: Option<u32>,
 }
@@ -1005,6 +2636,38 @@ pub struct ChannelStats {
 #[derive(Deserialize, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[serde(untagged)]
 /// A channel reference.
+///
+/// Videos reference their channel by ID, unless the API included channel metadata,
+/// such as when requesting [`ExtraVideoInfo::ChannelStats`].
+///
+/// # Examples
+///
+/// ```rust
+/// use holodex::model::{Video, VideoChannel};
+///
+/// let video: Video = serde_json::from_str(r#"{
+///     "id": "IhiievWaZMI", "title": "Graduation", "type": "stream", "status": "past",
+///     "available_at": "2021-07-01T12:00:00Z", "channel_id": "UCS9uQI-jC3DE0L4IpXyvr6w"
+/// }"#)?;
+/// assert!(matches!(video.channel, VideoChannel::Id(_)));
+///
+/// let video: Video = serde_json::from_str(r#"{
+///     "id": "IhiievWaZMI", "title": "Graduation", "type": "stream", "status": "past",
+///     "available_at": "2021-07-01T12:00:00Z",
+///     "channel": {
+///         "id": "UCS9uQI-jC3DE0L4IpXyvr6w", "name": "Coco Ch. 桐生ココ", "type": "vtuber",
+///         "photo": "https://example.com/coco.png", "org": "Hololive", "suborg": "a Hololive",
+///         "banner": "https://example.com/banner.png", "inactive": true,
+///         "video_count": "1234", "subscriber_count": "1500000", "view_count": 1000000, "clip_count": 10
+///     }
+/// }"#)?;
+///
+/// match video.channel {
+///     VideoChannel::Min(channel) => assert_eq!(channel.stats.subscriber_count, Some(1_500_000)),
+///     VideoChannel::Id(_) => panic!("Channel metadata was not parsed"),
+/// }
+/// # Ok::<(), serde_json::Error>(())
+/// ```
 pub enum VideoChannel {
     /// A channel ID.
     Id(ChannelId),
@@ -1013,6 +2676,20 @@ pub enum VideoChannel {
 }
 
 impl VideoChannel {
+    #[inline]
+    #[must_use]
+    /// Create a channel reference from a channel ID.
+    pub const fn from_id(id: ChannelId) -> Self {
+        Self::Id(id)
+    }
+
+    #[inline]
+    #[must_use]
+    /// Create a channel reference from some channel metadata.
+    pub const fn from_min(channel: ChannelMin) -> Self {
+        Self::Min(channel)
+    }
+
     #[inline]
     #[must_use]
     /// Returns the channel ID.
@@ -1022,6 +2699,30 @@ impl VideoChannel {
             Self::Min(d) => &d.id,
         }
     }
+
+    #[inline]
+    #[must_use]
+    /// Returns the channel name, if the reference contains channel metadata.
+    pub fn name(&self) -> Option<&str> {
+        match self {
+            Self::Id(_) => None,
+            Self::Min(d) => Some(&d.name),
+        }
+    }
+}
+
+impl From<ChannelId> for VideoChannel {
+    #[inline]
+    fn from(id: ChannelId) -> Self {
+        Self::from_id(id)
+    }
+}
+
+impl From<ChannelMin> for VideoChannel {
+    #[inline]
+    fn from(channel: ChannelMin) -> Self {
+        Self::from_min(channel)
+    }
 }
 
 #[non_exhaustive]
@@ -1029,13 +2730,31 @@ impl VideoChannel {
 #[derive(Deserialize, Serialize, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[serde(rename_all = "lowercase")]
 /// Different types of channels.
+///
+/// # Examples
+///
+/// Types added to the API later are parsed as [`Unknown`][`Self::Unknown`],
+/// instead of failing to parse the whole response.
+/// ```rust
+/// use holodex::model::ChannelType;
+///
+/// let types: Vec<ChannelType> = serde_json::from_str(r#"["vtuber", "agency"]"#)?;
+/// assert_eq!(types, [ChannelType::VTuber, ChannelType::Unknown]);
+/// assert!(serde_json::to_string(&ChannelType::Unknown).is_err());
+/// # Ok::<(), serde_json::Error>(())
+/// ```
 pub enum ChannelType {
-    /// A VTuber that provides content, such as streams or videos.
+    /// A `VTuber` that provides content, such as streams or videos.
     VTuber,
     /// A channel that takes content from a `VTuber` and edits it to make it more accessible.
     Subber,
+    #[serde(other)]
+    #[serde(skip_serializing)]
+    /// A type not known to this crate. This cannot be used in filters, and fails to serialize.
+    Unknown,
 }
 
+#[non_exhaustive]
 #[derive(Deserialize, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 /// A struct containing information about a video and any possible extra metadata that was requested.
 pub struct VideoFull {
@@ -1057,18 +2776,22 @@ pub struct VideoFull {
     pub simulcasts: Vec<Video>,
     #[serde(default)]
     /// Any channels that were mentioned in this video's description.
+    ///
+    /// These are not repeated in [`Video::mentions`] of [`video`][`Self::video`].
     pub mentions: Vec<ChannelMin>,
 
-    #[serde(default)]
-    #[serde(rename = "songcount")]
-    /// How many songs were sung in this video.
-    pub song_count: Option<u32>,
     #[serde(default)]
     /// Songs that were sung in this video.
+    ///
+    /// The number of songs is reported in [`Video::song_count`], which may be set even if
+    /// the songs themselves were not requested, see [`has_songs`][`Self::has_songs`].
     pub songs: Vec<Song>,
 
     #[serde(default)]
     /// Comments posted on this video.
+    ///
+    /// Only included when requested, such as with [`Client::video_with_timestamps`][`crate::Client::video_with_timestamps`],
+    /// in which case all indexed comments are included at once, as the API does not paginate them.
     pub comments: Vec<Comment>,
 
     #[serde(default)]
@@ -1077,6 +2800,227 @@ pub struct VideoFull {
     pub related: Vec<Video>,
 }
 
+impl VideoFull {
+    #[must_use]
+    /// Wrap a video with no extra metadata, which can be added through the public fields.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::{TimeZone, Utc};
+    /// use holodex::model::{id::ChannelId, Comment, Video, VideoFull, VideoStatus, VideoType};
+    ///
+    /// let video = Video::new(
+    ///     "IhiievWaZMI".parse()?,
+    ///     "Graduation",
+    ///     VideoType::Stream,
+    ///     VideoStatus::Past,
+    ///     Utc.with_ymd_and_hms(2021, 7, 1, 12, 0, 0).unwrap(),
+    ///     "UCS9uQI-jC3DE0L4IpXyvr6w".parse::<ChannelId>()?,
+    /// );
+    ///
+    /// let mut full = VideoFull::new(video);
+    /// full.comments.push(Comment::new("UgzXb0HdWo4ZhlNGVU94AaABAg", "12:34 Start"));
+    ///
+    /// assert_eq!(full.comment_count(), 1);
+    /// assert!(full.clips.is_empty());
+    /// # Ok::<(), holodex::errors::Error>(())
+    /// ```
+    pub const fn new(video: Video) -> Self {
+        Self {
+            video,
+            clips: Vec::new(),
+            sources: Vec::new(),
+            refers: Vec::new(),
+            simulcasts: Vec::new(),
+            mentions: Vec::new(),
+            songs: Vec::new(),
+            comments: Vec::new(),
+            related: Vec::new(),
+        }
+    }
+
+    #[must_use]
+    /// Split the video into one [`CommentSearchResult`] per comment, each with its own copy of the video.
+    ///
+    /// This flattens the results of [`Client::search_comments`][`crate::Client::search_comments`],
+    /// which returns the matching comments grouped by the video they were left on.
+    /// Comments without a [`video_id`][`Comment::video_id`] are given the ID of the video.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use holodex::model::VideoFull;
+    ///
+    /// let video: VideoFull = serde_json::from_str(r#"{
+    ///     "id": "IhiievWaZMI", "title": "Graduation", "type": "stream", "status": "past",
+    ///     "available_at": "2021-07-01T12:00:00Z", "channel_id": "UCS9uQI-jC3DE0L4IpXyvr6w",
+    ///     "comments": [
+    ///         { "comment_key": "UgzXb0HdWo4ZhlNGVU94AaABAg", "message": "peko" },
+    ///         { "comment_key": "UgwKfyDqZDc1V9Xf3dB4AaABAg", "message": "pekopeko" }
+    ///     ]
+    /// }"#)?;
+    ///
+    /// let results = video.into_comment_results();
+    ///
+    /// assert_eq!(results.len(), 2);
+    /// assert_eq!(results[1].video.title, "Graduation");
+    /// assert_eq!(results[1].comment.message, "pekopeko");
+    /// assert_eq!(results[1].comment.video_id, Some(results[1].video.id.clone()));
+    /// # Ok::<(), serde_json::Error>(())
+    /// ```
+    pub fn into_comment_results(self) -> Vec<CommentSearchResult> {
+        let video = self.video;
+
+        self.comments
+            .into_iter()
+            .map(|mut comment| {
+                comment.video_id.get_or_insert_with(|| video.id.clone());
+
+                CommentSearchResult {
+                    video: video.clone(),
+                    comment,
+                }
+            })
+            .collect()
+    }
+
+    #[must_use]
+    #[inline]
+    /// How many comments were included with the video.
+    ///
+    /// Comments are only included when requested, see [`comments`][`Self::comments`].
+    pub const fn comment_count(&self) -> usize {
+        self.comments.len()
+    }
+
+    #[must_use]
+    /// Get the comments ordered by the earliest timestamp in each of them,
+    /// as in a chronological list of chapters.
+    ///
+    /// Comments without any timestamps are placed last, in their original order.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use holodex::model::VideoFull;
+    ///
+    /// let video: VideoFull = serde_json::from_str(r#"{
+    ///     "id": "IhiievWaZMI",
+    ///     "title": "Karaoke",
+    ///     "type": "stream",
+    ///     "status": "past",
+    ///     "available_at": "2021-07-01T12:00:00Z",
+    ///     "channel_id": "UCS9uQI-jC3DE0L4IpXyvr6w",
+    ///     "comments": [
+    ///         { "comment_key": "a", "message": "Great stream!" },
+    ///         { "comment_key": "b", "message": "1:02:03 Encore\n45:00 Second song" },
+    ///         { "comment_key": "c", "message": "Thanks for the timestamps" },
+    ///         { "comment_key": "d", "message": "12:34 First song" }
+    ///     ]
+    /// }"#)?;
+    ///
+    /// let keys: Vec<_> = video
+    ///     .comments_by_timestamp()
+    ///     .iter()
+    ///     .map(|comment| comment.comment_key.as_str())
+    ///     .collect();
+    ///
+    /// assert_eq!(video.comment_count(), 4);
+    /// assert_eq!(keys, ["d", "b", "a", "c"]);
+    /// # Ok::<(), serde_json::Error>(())
+    /// ```
+    pub fn comments_by_timestamp(&self) -> Vec<&Comment> {
+        let mut comments: Vec<_> = self
+            .comments
+            .iter()
+            .map(|comment| {
+                let earliest = comment.timestamps().into_iter().map(|(_, s)| s).min();
+                (comment, earliest)
+            })
+            .collect();
+
+        // The sort is stable, so comments without timestamps keep their original order.
+        comments.sort_by_key(|&(_, earliest)| (earliest.is_none(), earliest));
+
+        comments.into_iter().map(|(comment, _)| comment).collect()
+    }
+
+    #[must_use]
+    #[inline]
+    /// Returns `true` if any songs were sung in this video.
+    ///
+    /// This checks both the reported song count and the list of [`songs`][`Self::songs`],
+    /// since the list is only filled in when [`ExtraVideoInfo::Songs`] was requested.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use holodex::model::VideoFull;
+    ///
+    /// let video: VideoFull = serde_json::from_str(r#"{
+    ///     "id": "IhiievWaZMI", "title": "Karaoke", "type": "stream", "status": "past",
+    ///     "available_at": "2021-07-01T12:00:00Z", "channel_id": "UCS9uQI-jC3DE0L4IpXyvr6w",
+    ///     "songcount": 12
+    /// }"#)?;
+    ///
+    /// assert_eq!(video.video.song_count, Some(12));
+    /// assert!(video.songs.is_empty());
+    /// assert!(video.has_songs());
+    /// # Ok::<(), serde_json::Error>(())
+    /// ```
+    pub fn has_songs(&self) -> bool {
+        self.video.song_count.unwrap_or(0) > 0 || !self.songs.is_empty()
+    }
+
+    #[must_use]
+    /// Get the songs sung in this video, sorted by when in the video they started.
+    pub fn songs_sorted(&self) -> Vec<&Song> {
+        let mut songs: Vec<&Song> = self.songs.iter().collect();
+        songs.sort_by_key(|song| song.start);
+        songs
+    }
+
+    #[must_use]
+    /// Get the song being sung at the given offset into the video, if any.
+    ///
+    /// A song is considered to be playing from its [`start`][`Song::start`] (inclusive)
+    /// until its [`end`][`Song::end`] (exclusive).
+    /// If several songs overlap at the given offset, the one that started first is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::Duration;
+    /// use holodex::model::VideoFull;
+    ///
+    /// let video: VideoFull = serde_json::from_str(r#"{
+    ///     "id": "IhiievWaZMI",
+    ///     "title": "Karaoke",
+    ///     "type": "stream",
+    ///     "available_at": "2021-07-01T12:00:00Z",
+    ///     "status": "past",
+    ///     "channel_id": "UCS9uQI-jC3DE0L4IpXyvr6w",
+    ///     "songs": [
+    ///         { "name": "Second", "original_artist": "B", "start": 300, "end": 500 },
+    ///         { "name": "First", "original_artist": "A", "start": 100, "end": 300 }
+    ///     ]
+    /// }"#)?;
+    ///
+    /// assert!(video.song_at(Duration::seconds(99)).is_none());
+    /// assert_eq!(video.song_at(Duration::seconds(100)).unwrap().name, "First");
+    /// assert_eq!(video.song_at(Duration::seconds(300)).unwrap().name, "Second");
+    /// assert!(video.song_at(Duration::seconds(500)).is_none());
+    /// # Ok::<(), serde_json::Error>(())
+    /// ```
+    pub fn song_at(&self, offset: Duration) -> Option<&Song> {
+        self.songs
+            .iter()
+            .filter(|song| song.start <= offset && offset < song.end)
+            .min_by_key(|song| song.start)
+    }
+}
+
 #[derive(
     Deserialize, Serialize, Default, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash,
 )]
@@ -1093,8 +3037,119 @@ pub struct VideoLiveInfo {
     pub live_viewers: Option<u32>,
 }
 
+impl VideoLiveInfo {
+    #[inline]
+    #[must_use]
+    /// Create livestream metadata with no values set, to fill in with the `with_*` methods.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::{TimeZone, Utc};
+    /// use holodex::model::VideoLiveInfo;
+    ///
+    /// let start = Utc.with_ymd_and_hms(2021, 7, 1, 12, 0, 0).unwrap();
+    /// let live_info = VideoLiveInfo::new()
+    ///     .with_start_scheduled(start)
+    ///     .with_start_actual(start)
+    ///     .with_live_viewers(25_000);
+    ///
+    /// assert_eq!(live_info.start_actual, Some(start));
+    /// assert_eq!(live_info.end_actual, None);
+    /// assert_eq!(live_info.live_viewers, Some(25_000));
+    /// ```
+    pub const fn new() -> Self {
+        Self {
+            start_scheduled: None,
+            start_actual: None,
+            end_actual: None,
+            live_viewers: None,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// Set when the stream is scheduled to start.
+    pub const fn with_start_scheduled(mut self, start_scheduled: DateTime<Utc>) -> Self {
+        self.start_scheduled = Some(start_scheduled);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    /// Set when the stream actually started.
+    pub const fn with_start_actual(mut self, start_actual: DateTime<Utc>) -> Self {
+        self.start_actual = Some(start_actual);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    /// Set when the stream ended.
+    pub const fn with_end_actual(mut self, end_actual: DateTime<Utc>) -> Self {
+        self.end_actual = Some(end_actual);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    /// Set the amount of viewers the stream has.
+    pub const fn with_live_viewers(mut self, live_viewers: u32) -> Self {
+        self.live_viewers = Some(live_viewers);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns `true` if none of the livestream metadata is present,
+    /// such as for clips or when the API did not include it.
+    pub const fn is_empty(&self) -> bool {
+        self.start_scheduled.is_none()
+            && self.start_actual.is_none()
+            && self.end_actual.is_none()
+            && self.live_viewers.is_none()
+    }
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+/// A minimal summary of a live or upcoming video, used when polling many channels at once.
+///
+/// # Examples
+///
+/// ```rust
+/// use holodex::model::{LiveStatus, VideoStatus};
+///
+/// let status: LiveStatus = serde_json::from_str(r#"{
+///     "id": "IhiievWaZMI", "title": "Ignored", "type": "stream", "status": "live",
+///     "available_at": "2021-07-01T12:00:00Z",
+///     "channel": { "id": "UCS9uQI-jC3DE0L4IpXyvr6w", "name": "Ignored as well" }
+/// }"#)?;
+///
+/// assert_eq!(status.channel_id, "UCS9uQI-jC3DE0L4IpXyvr6w");
+/// assert_eq!(status.status, VideoStatus::Live);
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+pub struct LiveStatus {
+    /// The ID of the video.
+    pub id: VideoId,
+    #[serde(rename = "channel", alias = "channel_id")]
+    #[serde(deserialize_with = "serializers::channel_id_or_min")]
+    /// The ID of the channel the video was uploaded by.
+    pub channel_id: ChannelId,
+    /// The status of the video.
+    pub status: VideoStatus,
+    #[serde(default)]
+    /// When the video went live or will go live, if known.
+    pub available_at: Option<DateTime<Utc>>,
+}
+
+#[non_exhaustive]
 #[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 /// A comment that was left on a video.
+///
+/// The API does not report which language a comment is written in.
+/// Language filters in a [`CommentSearch`] are applied server-side to the videos
+/// the comments were left on, not to the comments themselves.
 pub struct Comment {
     /// The ID of the comment.
     pub comment_key: String,
@@ -1105,12 +3160,112 @@ pub struct Comment {
     pub message: String,
 }
 
+impl Comment {
+    #[must_use]
+    /// Create a comment that is not tied to any video, which can be set through [`video_id`][`Self::video_id`].
+    pub fn new(comment_key: &str, message: &str) -> Self {
+        Self {
+            comment_key: comment_key.to_owned(),
+            video_id: None,
+            message: message.to_owned(),
+        }
+    }
+
+    #[must_use]
+    /// Get a link to each timestamp in the comment's message, as `(label, url)` pairs.
+    ///
+    /// Timestamps are written as `m:ss` or `h:mm:ss`, and the label is the timestamp as written.
+    /// The links point to the comment's video at that time,
+    /// so if the comment has no [`video_id`][`Self::video_id`], no links are returned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use holodex::model::Comment;
+    ///
+    /// let mut comment: Comment = serde_json::from_str(r#"{
+    ///     "comment_key": "UgzXb0HdWo4ZhlNGVU94AaABAg",
+    ///     "video_id": "IhiievWaZMI",
+    ///     "message": "12:34 Start\n1:02:03 Karaoke"
+    /// }"#)?;
+    ///
+    /// assert_eq!(comment.timestamp_links(), [
+    ///     ("12:34".to_owned(), "https://youtu.be/IhiievWaZMI?t=754".to_owned()),
+    ///     ("1:02:03".to_owned(), "https://youtu.be/IhiievWaZMI?t=3723".to_owned()),
+    /// ]);
+    ///
+    /// comment.video_id = None;
+    /// assert!(comment.timestamp_links().is_empty());
+    /// # Ok::<(), serde_json::Error>(())
+    /// ```
+    pub fn timestamp_links(&self) -> Vec<(String, String)> {
+        let Some(video_id) = &self.video_id else {
+            return Vec::new();
+        };
+
+        self.timestamps()
+            .into_iter()
+            .map(|(label, seconds)| {
+                (
+                    label.to_owned(),
+                    format!("{}?t={}", video_id.short_url(), seconds),
+                )
+            })
+            .collect()
+    }
+
+    /// Find each timestamp written as `m:ss` or `h:mm:ss` in the message,
+    /// along with how many seconds into the video it points to.
+    fn timestamps(&self) -> Vec<(&str, u64)> {
+        #[allow(clippy::expect_used)]
+        let regex = Regex::new(r"(?-u:\b)(?:([0-9]{1,2}):)?([0-9]{1,3}):([0-5][0-9])(?-u:\b)")
+            .expect("Timestamp regex broke.");
+
+        regex
+            .captures_iter(&self.message)
+            .filter_map(|captures| {
+                let part = |index| {
+                    captures
+                        .get(index)
+                        .map_or(Some(0), |part| part.as_str().parse::<u64>().ok())
+                };
+                let seconds = part(1)? * 3600 + part(2)? * 60 + part(3)?;
+
+                Some((captures.get(0)?.as_str(), seconds))
+            })
+            .collect()
+    }
+}
+
 impl Display for Comment {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}", self.message)
     }
 }
 
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+/// A comment matching a comment search, along with the video it was left on.
+///
+/// Created with [`VideoFull::into_comment_results`],
+/// or returned by [`Client::search_comments_flat`][`crate::Client::search_comments_flat`].
+pub struct CommentSearchResult {
+    /// The video the comment was left on.
+    pub video: Video,
+    /// The comment that matched the search.
+    pub comment: Comment,
+}
+
+impl CommentSearchResult {
+    #[inline]
+    #[must_use]
+    /// Pair a comment with the video it was left on.
+    pub const fn new(video: Video, comment: Comment) -> Self {
+        Self { video, comment }
+    }
+}
+
+#[non_exhaustive]
 #[derive(Deserialize, Serialize, Debug, Clone, Eq, PartialOrd, Ord)]
 /// A song that was played in a video.
 pub struct Song {
@@ -1134,6 +3289,37 @@ pub struct Song {
     pub end: Duration,
 }
 
+/// Songs are compared by what was played, ignoring [`start`][`Song::start`] and [`end`][`Song::end`],
+/// so the same song performed twice in a video is considered equal.
+///
+/// The [`Hash`][`std::hash::Hash`] implementation hashes the same fields,
+/// so equal songs always have equal hashes.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::{collections::HashSet, hash::{BuildHasher, RandomState}};
+/// use holodex::model::Song;
+///
+/// let opening: Song = serde_json::from_str(r#"{
+///     "name": "Shiny Smily Story", "original_artist": "hololive IDOL PROJECT",
+///     "art": null, "itunesid": 1543216541, "start": 120, "end": 360
+/// }"#)?;
+///
+/// let mut encore = opening.clone();
+/// encore.start = chrono::Duration::seconds(7200);
+/// encore.end = chrono::Duration::seconds(7440);
+///
+/// let state = RandomState::new();
+/// assert_eq!(opening, encore);
+/// assert_eq!(state.hash_one(&opening), state.hash_one(&encore));
+/// assert_eq!(HashSet::from([opening.clone(), encore]).len(), 1);
+///
+/// let mut cover = opening.clone();
+/// cover.artist = "Houshou Marine".to_owned();
+/// assert_ne!(opening, cover);
+/// # Ok::<(), serde_json::Error>(())
+/// ```
 impl PartialEq for Song {
     fn eq(&self, other: &Self) -> bool {
         self.name == other.name
@@ -1152,8 +3338,40 @@ impl std::hash::Hash for Song {
     }
 }
 
+impl Song {
+    #[must_use]
+    /// Create a song with no artwork or iTunes ID.
+    pub fn new(name: &str, artist: &str, start: Duration, end: Duration) -> Self {
+        Self {
+            name: name.to_owned(),
+            artist: artist.to_owned(),
+            artwork: None,
+            itunes_id: None,
+            start,
+            end,
+        }
+    }
+}
+
 impl Display for Song {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{} by {}", self.name, self.artist)
     }
 }
+
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+/// A topic that videos can be tagged with.
+pub struct Topic {
+    /// The ID of the topic, as used when filtering by topic.
+    pub id: String,
+    #[serde(default)]
+    #[serde(deserialize_with = "serializers::number_or_string")]
+    /// How many videos have been tagged with the topic, if known.
+    pub count: Option<u32>,
+}
+
+impl Display for Topic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(&self.id)
+    }
+}
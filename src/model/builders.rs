@@ -2,7 +2,7 @@
 
 use std::fmt::Display;
 
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Duration, Utc};
 use serde::{self, Serialize};
 
 use crate::errors::Error;
@@ -31,11 +31,43 @@ impl VideoFilterBuilder {
     #[inline]
     #[must_use]
     /// Request extra information to be included with each video.
+    ///
+    /// This replaces the current list, including the default of
+    /// [[`LiveInfo`][`ExtraVideoInfo::LiveInfo`]].
+    /// Use [`add_include`][`Self::add_include`] to keep it.
     pub fn include(mut self, include: &[ExtraVideoInfo]) -> Self {
         self.filter.include = include.to_vec();
         self
     }
 
+    #[inline]
+    #[must_use]
+    /// Request a piece of extra information to be included with each video,
+    /// in addition to any already requested.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use holodex::model::{builders::VideoFilterBuilder, ExtraVideoInfo};
+    ///
+    /// let filter = VideoFilterBuilder::new()
+    ///     .add_include(ExtraVideoInfo::Songs)
+    ///     .add_include(ExtraVideoInfo::Songs)
+    ///     .build();
+    /// assert_eq!(filter.include, [ExtraVideoInfo::LiveInfo, ExtraVideoInfo::Songs]);
+    ///
+    /// let filter = VideoFilterBuilder::new()
+    ///     .include(&[ExtraVideoInfo::Songs])
+    ///     .build();
+    /// assert_eq!(filter.include, [ExtraVideoInfo::Songs]);
+    /// ```
+    pub fn add_include(mut self, include: ExtraVideoInfo) -> Self {
+        if !self.filter.include.contains(&include) {
+            self.filter.include.push(include);
+        }
+        self
+    }
+
     #[inline]
     #[must_use]
     /// Enable pagination.
@@ -117,7 +149,54 @@ impl VideoFilterBuilder {
     #[must_use]
     /// Only return videos scheduled to go live within the given amount of hours.
     pub const fn max_upcoming_hours(mut self, hours: u32) -> Self {
-        self.filter.max_upcoming_hours = hours;
+        self.filter.max_upcoming_hours = Some(hours);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    /// Do not return any videos that are scheduled to go live in the future.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use holodex::model::builders::VideoFilterBuilder;
+    ///
+    /// let filter = VideoFilterBuilder::new().exclude_upcoming().build();
+    /// assert_eq!(filter.max_upcoming_hours, Some(0));
+    ///
+    /// let query = serde_urlencoded::to_string(&filter).unwrap();
+    /// assert!(query.split('&').any(|pair| pair == "max_upcoming_hours=0"));
+    /// ```
+    pub const fn exclude_upcoming(self) -> Self {
+        self.max_upcoming_hours(0)
+    }
+
+    #[inline]
+    #[must_use]
+    /// Only return videos scheduled to go live within the given duration.
+    ///
+    /// The duration is rounded up to whole hours, negative durations are treated as zero.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::Duration;
+    /// use holodex::model::builders::VideoFilterBuilder;
+    ///
+    /// let filter = VideoFilterBuilder::new().max_upcoming(Duration::days(2)).build();
+    /// assert_eq!(filter.max_upcoming_hours, Some(48));
+    ///
+    /// let filter = VideoFilterBuilder::new().max_upcoming(Duration::minutes(90)).build();
+    /// assert_eq!(filter.max_upcoming_hours, Some(2));
+    /// ```
+    pub fn max_upcoming(mut self, duration: Duration) -> Self {
+        const MILLIS_PER_HOUR: i64 = 60 * 60 * 1000;
+
+        let millis = duration.num_milliseconds().max(0);
+        let hours = millis / MILLIS_PER_HOUR + i64::from(millis % MILLIS_PER_HOUR != 0);
+
+        self.filter.max_upcoming_hours = Some(u32::try_from(hours).unwrap_or(u32::MAX));
         self
     }
 
@@ -149,11 +228,92 @@ impl VideoFilterBuilder {
     #[inline]
     #[must_use]
     /// Only return videos with any of the given statuses.
+    ///
+    /// An empty list returns videos of every status, including [`New`][`VideoStatus::New`]
+    /// and [`Missing`][`VideoStatus::Missing`] ones.
     pub fn status(mut self, status: &[VideoStatus]) -> Self {
         self.filter.status = status.to_vec();
         self
     }
 
+    #[must_use]
+    /// Do not return videos that are no longer available.
+    ///
+    /// If no statuses have been set, this only allows [`VideoStatus::active_statuses`],
+    /// which also excludes videos that haven't been indexed yet.
+    /// Otherwise, [`Missing`][`VideoStatus::Missing`] is removed from the set statuses.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use holodex::model::{builders::VideoFilterBuilder, VideoStatus};
+    ///
+    /// let filter = VideoFilterBuilder::new().exclude_missing().build();
+    /// assert_eq!(filter.status, VideoStatus::active_statuses());
+    ///
+    /// let filter = VideoFilterBuilder::new()
+    ///     .status(&[VideoStatus::Past, VideoStatus::Missing])
+    ///     .exclude_missing()
+    ///     .build();
+    /// assert_eq!(filter.status, [VideoStatus::Past]);
+    /// ```
+    pub fn exclude_missing(mut self) -> Self {
+        if self.filter.status.is_empty() {
+            self.filter.status = VideoStatus::active_statuses().to_vec();
+        } else {
+            self.filter
+                .status
+                .retain(|status| *status != VideoStatus::Missing);
+        }
+
+        self
+    }
+
+    #[must_use]
+    /// Only return streams that have ended, such as when browsing archives.
+    ///
+    /// This sets the status to [`Past`][`VideoStatus::Past`] and the type to [`Stream`][`VideoType::Stream`],
+    /// and can be combined with the other setters.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use holodex::model::{builders::VideoFilterBuilder, Organisation, VideoStatus, VideoType};
+    ///
+    /// let filter = VideoFilterBuilder::new()
+    ///     .organisation(Organisation::Nijisanji)
+    ///     .past_only()
+    ///     .build();
+    ///
+    /// assert_eq!(filter.status, [VideoStatus::Past]);
+    /// assert_eq!(filter.video_type, VideoType::Stream);
+    /// assert_eq!(filter.org, Some(Organisation::Nijisanji));
+    /// ```
+    pub fn past_only(self) -> Self {
+        self.status(&[VideoStatus::Past])
+            .video_type(VideoType::Stream)
+    }
+
+    #[must_use]
+    /// Only return streams that are scheduled but have not started yet.
+    ///
+    /// This sets the status to [`Upcoming`][`VideoStatus::Upcoming`] and the type to [`Stream`][`VideoType::Stream`],
+    /// and can be combined with the other setters.
+    pub fn upcoming_only(self) -> Self {
+        self.status(&[VideoStatus::Upcoming])
+            .video_type(VideoType::Stream)
+    }
+
+    #[must_use]
+    /// Only return streams that are live right now.
+    ///
+    /// This sets the status to [`Live`][`VideoStatus::Live`] and the type to [`Stream`][`VideoType::Stream`],
+    /// and can be combined with the other setters.
+    pub fn live_only(self) -> Self {
+        self.status(&[VideoStatus::Live])
+            .video_type(VideoType::Stream)
+    }
+
     #[inline]
     #[must_use]
     /// Only return videos made available after the given time.
@@ -169,6 +329,31 @@ impl VideoFilterBuilder {
     pub fn build(self) -> VideoFilter {
         self.filter
     }
+
+    /// Consume the builder, returning the constructed filter if it is valid.
+    ///
+    /// Unlike [`build`][`Self::build`], this checks the filter with [`VideoFilter::validate`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use holodex::model::{builders::VideoFilterBuilder, VideoSortingCriteria, VideoType};
+    ///
+    /// let result = VideoFilterBuilder::new()
+    ///     .video_type(VideoType::Clip)
+    ///     .sort_by(VideoSortingCriteria::StartActual)
+    ///     .try_build();
+    ///
+    /// assert!(result.is_err());
+    /// ```
+    ///
+    /// # Errors
+    /// Will return [`Error::FilterCreationError`] if the filter combines incompatible options.
+    pub fn try_build(self) -> Result<VideoFilter, Error> {
+        self.filter.validate()?;
+
+        Ok(self.filter)
+    }
 }
 
 impl Display for VideoFilterBuilder {
@@ -183,6 +368,30 @@ impl From<VideoFilterBuilder> for VideoFilter {
     }
 }
 
+/// Continue building from an existing filter.
+///
+/// # Examples
+///
+/// ```rust
+/// use holodex::model::{builders::VideoFilterBuilder, Organisation, VideoFilter};
+///
+/// let base = VideoFilterBuilder::new()
+///     .organisation(Organisation::Hololive)
+///     .limit(10)
+///     .build();
+///
+/// let filter = VideoFilterBuilder::from(base.clone()).offset(10).build();
+///
+/// assert_eq!(filter.org, Some(Organisation::Hololive));
+/// assert_eq!(filter.limit, 10);
+/// assert_eq!(filter.offset, 10);
+/// ```
+impl From<VideoFilter> for VideoFilterBuilder {
+    fn from(filter: VideoFilter) -> Self {
+        Self { filter }
+    }
+}
+
 #[derive(Serialize, Debug, Default, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
 /// Builder for creating a [`ChannelFilter`].
 pub struct ChannelFilterBuilder {
@@ -216,6 +425,21 @@ impl ChannelFilterBuilder {
     #[inline]
     #[must_use]
     /// Only return channels that uses any of the given languages as their main language.
+    ///
+    /// # Examples
+    ///
+    /// The languages are sent as a single comma-separated parameter.
+    /// ```rust
+    /// use holodex::model::{builders::ChannelFilterBuilder, Language};
+    ///
+    /// let filter = ChannelFilterBuilder::new()
+    ///     .language(&[Language::English, Language::Japanese])
+    ///     .build()?;
+    ///
+    /// let query = serde_urlencoded::to_string(&filter).unwrap();
+    /// assert!(query.split('&').any(|pair| pair == "lang=en%2Cja"));
+    /// # Ok::<(), holodex::errors::Error>(())
+    /// ```
     pub fn language(mut self, lang: &[Language]) -> Self {
         self.filter.languages = lang.to_vec();
         self
@@ -256,6 +480,16 @@ impl ChannelFilterBuilder {
         self
     }
 
+    #[inline]
+    #[must_use]
+    /// Leave out channels that are marked as inactive, such as graduated talents.
+    ///
+    /// This is done client-side, see [`ChannelFilter::exclude_inactive`] for details.
+    pub const fn exclude_inactive(mut self) -> Self {
+        self.filter.exclude_inactive = true;
+        self
+    }
+
     /// Consume the builder, returning the constructed filter.
     ///
     /// # Errors
@@ -276,6 +510,13 @@ impl ChannelFilterBuilder {
     }
 }
 
+/// Continue building from an existing filter.
+impl From<ChannelFilter> for ChannelFilterBuilder {
+    fn from(filter: ChannelFilter) -> Self {
+        Self { filter }
+    }
+}
+
 #[derive(Serialize, Debug, Default, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
 /// Builder for creating a [`VideoSearch`].
 pub struct VideoSearchBuilder {
@@ -364,6 +605,8 @@ impl VideoSearchBuilder {
     #[inline]
     #[must_use]
     /// Only return videos that are related to any of the given topics.
+    ///
+    /// Clips do not have topics, so this should not be combined with [`clips_only`][`Self::clips_only`].
     pub fn topics(mut self, topics: &[String]) -> Self {
         self.search.topics = topics.to_vec();
         self
@@ -377,6 +620,84 @@ impl VideoSearchBuilder {
         self
     }
 
+    #[must_use]
+    /// Only return clips, clearing any topics that have been set.
+    ///
+    /// Searching for topics and clips together is not supported, since clips do not have topics.
+    /// Setting [`topics`][`Self::topics`] after calling this makes the search invalid again,
+    /// which [`try_build`][`Self::try_build`] rejects.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use holodex::model::{builders::VideoSearchBuilder, VideoType};
+    ///
+    /// let search = VideoSearchBuilder::new()
+    ///     .topics(&["singing".to_owned()])
+    ///     .clips_only()
+    ///     .build();
+    ///
+    /// assert_eq!(search.types, [VideoType::Clip]);
+    /// assert!(search.topics.is_empty());
+    /// ```
+    pub fn clips_only(mut self) -> Self {
+        self.search.types = vec![VideoType::Clip];
+        self.search.topics.clear();
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    /// Only return videos made available at or after the given time.
+    pub const fn after(mut self, after: DateTime<Utc>) -> Self {
+        self.search.from = Some(after);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    /// Only return videos made available at or before the given time.
+    pub const fn before(mut self, before: DateTime<Utc>) -> Self {
+        self.search.to = Some(before);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    /// Only return videos made available within the given time window, inclusive.
+    ///
+    /// # Examples
+    ///
+    /// Search for streams mentioning Minecraft in March 2021.
+    /// ```rust
+    /// use chrono::{TimeZone, Utc};
+    /// use holodex::model::{builders::VideoSearchBuilder, VideoSearchCondition};
+    ///
+    /// let search = VideoSearchBuilder::new()
+    ///     .conditions(&[VideoSearchCondition::Text("Minecraft".to_owned())])
+    ///     .between(
+    ///         Utc.with_ymd_and_hms(2021, 3, 1, 0, 0, 0).unwrap(),
+    ///         Utc.with_ymd_and_hms(2021, 3, 31, 23, 59, 59).unwrap(),
+    ///     )
+    ///     .build();
+    ///
+    /// assert_eq!(
+    ///     serde_json::to_value(&search).unwrap(),
+    ///     serde_json::json!({
+    ///         "sort": "newest",
+    ///         "conditions": [{ "text": "Minecraft" }],
+    ///         "from": "2021-03-01T00:00:00Z",
+    ///         "to": "2021-03-31T23:59:59Z",
+    ///         "paginated": "true",
+    ///         "limit": 30,
+    ///         "offset": 0,
+    ///     })
+    /// );
+    /// ```
+    pub const fn between(self, from: DateTime<Utc>, to: DateTime<Utc>) -> Self {
+        self.after(from).before(to)
+    }
+
     #[inline]
     #[must_use]
     #[allow(clippy::missing_const_for_fn)]
@@ -384,6 +705,31 @@ impl VideoSearchBuilder {
     pub fn build(self) -> VideoSearch {
         self.search
     }
+
+    /// Consume the builder, returning the constructed search if it is valid.
+    ///
+    /// Unlike [`build`][`Self::build`], this checks the search with [`VideoSearch::validate`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use holodex::model::builders::VideoSearchBuilder;
+    ///
+    /// let result = VideoSearchBuilder::new()
+    ///     .clips_only()
+    ///     .topics(&["singing".to_owned()])
+    ///     .try_build();
+    ///
+    /// assert!(result.is_err());
+    /// ```
+    ///
+    /// # Errors
+    /// Will return [`Error::FilterCreationError`] if the search combines incompatible options.
+    pub fn try_build(self) -> Result<VideoSearch, Error> {
+        self.search.validate()?;
+
+        Ok(self.search)
+    }
 }
 
 impl From<VideoSearchBuilder> for VideoSearch {
@@ -392,6 +738,13 @@ impl From<VideoSearchBuilder> for VideoSearch {
     }
 }
 
+/// Continue building from an existing search.
+impl From<VideoSearch> for VideoSearchBuilder {
+    fn from(search: VideoSearch) -> Self {
+        Self { search }
+    }
+}
+
 #[derive(Serialize, Debug, Default, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
 /// Builder for creating a [`CommentSearch`].
 pub struct CommentSearchBuilder {
@@ -404,13 +757,31 @@ impl CommentSearchBuilder {
     /// Create a new `CommentSearchBuilder` with default values and the given substring to search for.
     pub fn new(search: &str) -> Self {
         Self {
-            search: CommentSearch {
-                search: search.to_owned(),
-                ..CommentSearch::default()
-            },
+            search: CommentSearch::from(search),
         }
     }
 
+    #[inline]
+    #[must_use]
+    /// Add another substring to search for.
+    ///
+    /// See [`CommentSearch::search`] for how multiple terms are handled.
+    pub fn term(mut self, term: &str) -> Self {
+        self.search.search.push(term.to_owned());
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    /// Search for the given substrings.
+    ///
+    /// This replaces any previously added search terms, including the one given to [`new`][`Self::new`].
+    /// See [`CommentSearch::search`] for how multiple terms are handled.
+    pub fn terms(mut self, terms: &[String]) -> Self {
+        self.search.search = terms.to_vec();
+        self
+    }
+
     #[inline]
     #[must_use]
     /// Enable pagination.
@@ -509,3 +880,27 @@ impl From<CommentSearchBuilder> for CommentSearch {
         builder.search
     }
 }
+
+/// Continue building from an existing search.
+impl From<CommentSearch> for CommentSearchBuilder {
+    fn from(search: CommentSearch) -> Self {
+        Self { search }
+    }
+}
+
+/// Start a search for the given substring, same as [`CommentSearchBuilder::new`].
+///
+/// # Examples
+///
+/// ```rust
+/// use holodex::model::{builders::CommentSearchBuilder, CommentSearch};
+///
+/// let search = CommentSearchBuilder::from("peko").build();
+/// assert_eq!(search.search, ["peko"]);
+/// assert_eq!(search, CommentSearch::from("peko"));
+/// ```
+impl From<&str> for CommentSearchBuilder {
+    fn from(search: &str) -> Self {
+        Self::new(search)
+    }
+}
@@ -0,0 +1,247 @@
+//! Helpers for removing duplicate videos from sequential fetches.
+//!
+//! Adjacent queries, such as sliding a [`from`][`super::VideoFilter::from`] window over time,
+//! usually overlap at their boundaries and return some of the same videos.
+//! [`VideoDeduplicator`] remembers the IDs of recently seen videos so that those can be skipped,
+//! and [`DedupByIdExt`] wraps it as an iterator adapter.
+
+use std::collections::{HashSet, VecDeque};
+
+use super::{id::VideoId, Video};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// Remembers the IDs of the most recently seen videos, up to a fixed capacity.
+///
+/// Once the capacity is reached, the ID that was first seen the longest time ago is forgotten,
+/// so the memory used stays bounded no matter how many videos pass through it.
+///
+/// # Examples
+///
+/// ```rust
+/// use holodex::model::{dedup::VideoDeduplicator, id::VideoId};
+///
+/// let first: VideoId = "IhiievWaZMI".parse()?;
+/// let second: VideoId = "v6o7LBrQs-I".parse()?;
+///
+/// let mut seen = VideoDeduplicator::with_capacity(1);
+///
+/// assert!(seen.insert(&first));
+/// assert!(!seen.insert(&first));
+///
+/// // The capacity is exceeded, so `first` is forgotten.
+/// assert!(seen.insert(&second));
+/// assert!(seen.insert(&first));
+/// # Ok::<(), holodex::errors::Error>(())
+/// ```
+pub struct VideoDeduplicator {
+    capacity: usize,
+    seen: HashSet<VideoId>,
+    order: VecDeque<VideoId>,
+}
+
+impl VideoDeduplicator {
+    /// How many IDs are remembered by default, enough to cover the overlap of several full pages.
+    pub const DEFAULT_CAPACITY: usize = 1000;
+
+    #[inline]
+    #[must_use]
+    /// Create a deduplicator remembering up to [`DEFAULT_CAPACITY`][`Self::DEFAULT_CAPACITY`] IDs.
+    pub fn new() -> Self {
+        Self::with_capacity(Self::DEFAULT_CAPACITY)
+    }
+
+    #[must_use]
+    /// Create a deduplicator remembering up to `capacity` IDs.
+    ///
+    /// A capacity of zero is treated as one.
+    pub fn with_capacity(capacity: usize) -> Self {
+        let capacity = capacity.max(1);
+
+        Self {
+            capacity,
+            seen: HashSet::with_capacity(capacity),
+            order: VecDeque::with_capacity(capacity),
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    /// How many IDs can be remembered at once.
+    pub const fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    #[inline]
+    #[must_use]
+    /// How many IDs are currently remembered.
+    pub fn len(&self) -> usize {
+        self.order.len()
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns `true` if no IDs are remembered.
+    pub fn is_empty(&self) -> bool {
+        self.order.is_empty()
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns `true` if `id` is currently remembered.
+    pub fn contains(&self, id: &VideoId) -> bool {
+        self.seen.contains(id)
+    }
+
+    /// Remember `id`, returning `true` if it was not already remembered.
+    pub fn insert(&mut self, id: &VideoId) -> bool {
+        if self.seen.contains(id) {
+            return false;
+        }
+
+        if self.order.len() >= self.capacity {
+            if let Some(oldest) = self.order.pop_front() {
+                self.seen.remove(&oldest);
+            }
+        }
+
+        self.seen.insert(id.clone());
+        self.order.push_back(id.clone());
+
+        true
+    }
+
+    #[inline]
+    /// Forget all remembered IDs.
+    pub fn clear(&mut self) {
+        self.seen.clear();
+        self.order.clear();
+    }
+}
+
+impl Default for VideoDeduplicator {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Items that [`DedupById`] can deduplicate.
+///
+/// Errors are never considered duplicates, so they are always passed through.
+pub trait VideoItem {
+    /// The ID of the video, if the item contains one.
+    fn video_id(&self) -> Option<&VideoId>;
+}
+
+impl VideoItem for Video {
+    #[inline]
+    fn video_id(&self) -> Option<&VideoId> {
+        Some(&self.id)
+    }
+}
+
+impl<E> VideoItem for Result<Video, E> {
+    #[inline]
+    fn video_id(&self) -> Option<&VideoId> {
+        self.as_ref().ok().map(|video| &video.id)
+    }
+}
+
+#[derive(Debug, Clone)]
+#[must_use = "iterator adapters are lazy and do nothing unless consumed"]
+/// An iterator that skips videos whose ID has recently been seen.
+///
+/// Created by [`DedupByIdExt::dedup_by_id`] and [`DedupByIdExt::dedup_by_id_window`].
+pub struct DedupById<I> {
+    iter: I,
+    seen: VideoDeduplicator,
+}
+
+impl<I> DedupById<I> {
+    #[inline]
+    #[must_use]
+    /// The deduplicator used to track which videos have been seen.
+    pub const fn deduplicator(&self) -> &VideoDeduplicator {
+        &self.seen
+    }
+}
+
+impl<I> Iterator for DedupById<I>
+where
+    I: Iterator,
+    I::Item: VideoItem,
+{
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let seen = &mut self.seen;
+
+        self.iter
+            .find(|item| item.video_id().is_none_or(|id| seen.insert(id)))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+}
+
+/// Extension trait adding deduplication by video ID to iterators of videos.
+///
+/// # Examples
+///
+/// Stitch two overlapping time windows into one continuous list.
+/// ```rust
+/// use holodex::model::{dedup::DedupByIdExt, Video};
+///
+/// let earlier: Vec<Video> = serde_json::from_str(r#"[
+///     { "id": "IhiievWaZMI", "title": "First", "type": "stream", "status": "past",
+///       "available_at": "2021-07-01T12:00:00Z", "channel_id": "UCS9uQI-jC3DE0L4IpXyvr6w" },
+///     { "id": "v6o7LBrQs-I", "title": "Boundary", "type": "stream", "status": "past",
+///       "available_at": "2021-07-02T00:00:00Z", "channel_id": "UCS9uQI-jC3DE0L4IpXyvr6w" }
+/// ]"#)?;
+/// let later: Vec<Video> = serde_json::from_str(r#"[
+///     { "id": "v6o7LBrQs-I", "title": "Boundary", "type": "stream", "status": "past",
+///       "available_at": "2021-07-02T00:00:00Z", "channel_id": "UCS9uQI-jC3DE0L4IpXyvr6w" },
+///     { "id": "kpgG2jIw6kQ", "title": "Last", "type": "stream", "status": "past",
+///       "available_at": "2021-07-02T12:00:00Z", "channel_id": "UCS9uQI-jC3DE0L4IpXyvr6w" }
+/// ]"#)?;
+///
+/// let titles: Vec<_> = earlier
+///     .into_iter()
+///     .chain(later)
+///     .dedup_by_id()
+///     .map(|video| video.title)
+///     .collect();
+///
+/// assert_eq!(titles, ["First", "Boundary", "Last"]);
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+pub trait DedupByIdExt: Iterator + Sized
+where
+    Self::Item: VideoItem,
+{
+    #[inline]
+    /// Skip videos whose ID has been seen among the last
+    /// [`DEFAULT_CAPACITY`][`VideoDeduplicator::DEFAULT_CAPACITY`] distinct videos.
+    fn dedup_by_id(self) -> DedupById<Self> {
+        self.dedup_by_id_window(VideoDeduplicator::DEFAULT_CAPACITY)
+    }
+
+    #[inline]
+    /// Skip videos whose ID has been seen among the last `window` distinct videos.
+    fn dedup_by_id_window(self, window: usize) -> DedupById<Self> {
+        DedupById {
+            iter: self,
+            seen: VideoDeduplicator::with_capacity(window),
+        }
+    }
+}
+
+impl<I> DedupByIdExt for I
+where
+    I: Iterator,
+    I::Item: VideoItem,
+{
+}
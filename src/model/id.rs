@@ -1,4 +1,31 @@
 //! Various types wrapping different IDs used in the API.
+//!
+//! The IDs are serialized as plain strings, so they can be used as keys of maps serialized to JSON.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use std::collections::HashMap;
+//! use holodex::model::id::{ChannelId, VideoId};
+//!
+//! let channel: ChannelId = "UCS9uQI-jC3DE0L4IpXyvr6w".parse()?;
+//! let video: VideoId = "IhiievWaZMI".parse()?;
+//!
+//! assert_eq!(serde_json::to_string(&channel)?, r#""UCS9uQI-jC3DE0L4IpXyvr6w""#);
+//! assert_eq!(serde_json::to_string(&video)?, r#""IhiievWaZMI""#);
+//!
+//! let latest = HashMap::from([(channel.clone(), video.clone())]);
+//! let json = serde_json::to_string(&latest)?;
+//! assert_eq!(json, r#"{"UCS9uQI-jC3DE0L4IpXyvr6w":"IhiievWaZMI"}"#);
+//!
+//! let parsed: HashMap<ChannelId, VideoId> = serde_json::from_str(&json)?;
+//! assert_eq!(parsed, latest);
+//!
+//! let titles = HashMap::from([(video, "Graduation".to_owned())]);
+//! let parsed: HashMap<VideoId, String> = serde_json::from_str(&serde_json::to_string(&titles)?)?;
+//! assert_eq!(parsed, titles);
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
 #![allow(clippy::module_name_repetitions)]
 
 use std::{convert::TryFrom, fmt::Display, ops::Deref, str::FromStr};
@@ -17,6 +44,12 @@ use crate::{
 #[cfg(feature = "streams")]
 use futures_core::Stream;
 
+const YOUTUBE_SHORT_URL: &str = "https://youtu.be/";
+const YOUTUBE_WATCH_URL: &str = "https://www.youtube.com/watch?v=";
+const YOUTUBE_CHANNEL_URL: &str = "https://www.youtube.com/channel/";
+const HOLODEX_WATCH_URL: &str = "https://holodex.net/watch/";
+const HOLODEX_CHANNEL_URL: &str = "https://holodex.net/channel/";
+
 #[cfg(not(feature = "sso"))]
 #[derive(Debug, Clone, PartialEq, Eq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
 /// The ID of a video.
@@ -128,6 +161,77 @@ impl VideoId {
 
         Ok(metadata.related.into_iter())
     }
+
+    #[inline]
+    #[must_use]
+    /// Get the normalized ID, the 11 characters identifying the video on `YouTube` and Holodex.
+    ///
+    /// IDs are normalized when parsed, so this is the same no matter if the ID was parsed
+    /// from a bare ID or a link to the video, and is what should be stored to refer to the video.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use holodex::model::id::VideoId;
+    ///
+    /// let video_id: VideoId = "https://www.youtube.com/watch?v=IhiievWaZMI&t=42s".parse()?;
+    /// assert_eq!(video_id.normalized(), "IhiievWaZMI");
+    /// # Ok::<(), holodex::errors::Error>(())
+    /// ```
+    pub fn normalized(&self) -> &str {
+        &self.0
+    }
+
+    #[inline]
+    #[must_use]
+    /// Get a short link to the video on `YouTube`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use holodex::model::id::VideoId;
+    ///
+    /// let video_id: VideoId = "IhiievWaZMI".parse()?;
+    /// assert_eq!(video_id.short_url(), "https://youtu.be/IhiievWaZMI");
+    /// # Ok::<(), holodex::errors::Error>(())
+    /// ```
+    pub fn short_url(&self) -> String {
+        format!("{}{}", YOUTUBE_SHORT_URL, self.0)
+    }
+
+    #[inline]
+    #[must_use]
+    /// Get a link to the watch page of the video on `YouTube`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use holodex::model::id::VideoId;
+    ///
+    /// let video_id: VideoId = "https://youtu.be/IhiievWaZMI".parse()?;
+    /// assert_eq!(video_id.watch_url(), "https://www.youtube.com/watch?v=IhiievWaZMI");
+    /// # Ok::<(), holodex::errors::Error>(())
+    /// ```
+    pub fn watch_url(&self) -> String {
+        format!("{}{}", YOUTUBE_WATCH_URL, self.0)
+    }
+
+    #[inline]
+    #[must_use]
+    /// Get a link to the video on Holodex.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use holodex::model::id::VideoId;
+    ///
+    /// let video_id: VideoId = "IhiievWaZMI".parse()?;
+    /// assert_eq!(video_id.holodex_url(), "https://holodex.net/watch/IhiievWaZMI");
+    /// # Ok::<(), holodex::errors::Error>(())
+    /// ```
+    pub fn holodex_url(&self) -> String {
+        format!("{}{}", HOLODEX_WATCH_URL, self.0)
+    }
 }
 
 impl Display for VideoId {
@@ -144,6 +248,71 @@ impl Deref for VideoId {
     }
 }
 
+/// Compare an ID against a string slice.
+///
+/// # Examples
+///
+/// ```rust
+/// use holodex::model::id::VideoId;
+///
+/// let id: VideoId = "IhiievWaZMI".parse()?;
+///
+/// assert_eq!(id, *"IhiievWaZMI");
+/// assert_eq!(id, "IhiievWaZMI");
+/// assert_eq!(id, "IhiievWaZMI".to_owned());
+/// assert_ne!(id, "something else");
+/// # Ok::<(), holodex::errors::Error>(())
+/// ```
+impl PartialEq<str> for VideoId {
+    fn eq(&self, other: &str) -> bool {
+        &**self == other
+    }
+}
+
+impl PartialEq<&str> for VideoId {
+    fn eq(&self, other: &&str) -> bool {
+        &**self == *other
+    }
+}
+
+impl PartialEq<String> for VideoId {
+    fn eq(&self, other: &String) -> bool {
+        &**self == other
+    }
+}
+
+impl AsRef<str> for VideoId {
+    fn as_ref(&self) -> &str {
+        self
+    }
+}
+
+/// Convert an ID into the raw ID string.
+///
+/// # Examples
+///
+/// ```rust
+/// use holodex::model::id::VideoId;
+///
+/// fn takes_str(id: impl AsRef<str>) -> usize {
+///     id.as_ref().len()
+/// }
+///
+/// let id: VideoId = "IhiievWaZMI".parse()?;
+/// assert_eq!(takes_str(&id), "IhiievWaZMI".len());
+///
+/// let raw: String = id.into();
+/// assert_eq!(raw, "IhiievWaZMI");
+/// # Ok::<(), holodex::errors::Error>(())
+/// ```
+impl From<VideoId> for String {
+    fn from(id: VideoId) -> Self {
+        // Only a no-op without the `sso` feature.
+        #[allow(clippy::useless_conversion)]
+        id.0.into()
+    }
+}
+
 impl TryFrom<String> for VideoId {
     type Error = Error;
 
@@ -152,6 +321,36 @@ impl TryFrom<String> for VideoId {
     }
 }
 
+/// Parse a video ID, either on its own or from a link to the video.
+///
+/// Links to `YouTube` and Holodex are supported, and any other parameters in them,
+/// such as playlists, timestamps or tracking parameters, are ignored,
+/// so the parsed ID is always just the 11 characters of the ID.
+///
+/// # Examples
+///
+/// ```rust
+/// use holodex::model::id::VideoId;
+///
+/// let links = [
+///     "IhiievWaZMI",
+///     "https://www.youtube.com/watch?v=IhiievWaZMI&list=PL1NeGg1woXqngm16RWSWBsdBWnvDyl_YV&index=3",
+///     "https://www.youtube.com/watch?list=PL1NeGg1woXqngm16RWSWBsdBWnvDyl_YV&v=IhiievWaZMI&t=42s",
+///     "https://m.youtube.com/watch?app=desktop&v=IhiievWaZMI",
+///     "https://youtu.be/IhiievWaZMI?si=A1b2C3d4E5f6G7h8&t=10",
+///     "https://www.youtube.com/shorts/IhiievWaZMI?feature=share",
+///     "https://www.youtube.com/live/IhiievWaZMI?si=A1b2C3d4E5f6G7h8",
+///     "https://holodex.net/watch/IhiievWaZMI#comments",
+/// ];
+///
+/// for link in links {
+///     let id: VideoId = link.parse()?;
+///     assert_eq!(id.normalized(), "IhiievWaZMI", "parsing {}", link);
+/// }
+///
+/// assert!("https://www.youtube.com/".parse::<VideoId>().is_err());
+/// # Ok::<(), holodex::errors::Error>(())
+/// ```
 impl FromStr for VideoId {
     type Err = Error;
 
@@ -160,14 +359,20 @@ impl FromStr for VideoId {
         #[allow(clippy::expect_used)]
         let regex =
             Regex::new(r"[0-9A-Za-z_-]{10}[048AEIMQUYcgkosw]").expect("Video ID regex broke.");
+        // Look where links put the ID first, so that other parameters such as playlist IDs are not mistaken for it.
+        #[allow(clippy::expect_used)]
+        let link_regex = Regex::new(
+            r"(?:[?&]v=|youtu\.be/|/(?:watch|shorts|live|embed|v)/)([0-9A-Za-z_-]{10}[048AEIMQUYcgkosw])(?:[^0-9A-Za-z_-]|$)",
+        )
+        .expect("Video link regex broke.");
+
+        let id = link_regex
+            .captures(s)
+            .and_then(|captures| captures.get(1))
+            .or_else(|| regex.find(s))
+            .ok_or_else(|| Error::InvalidVideoId(s.to_owned()))?;
 
-        Ok(Self(
-            regex
-                .find(s)
-                .ok_or_else(|| Error::InvalidVideoId(s.to_owned()))?
-                .as_str()
-                .into(),
-        ))
+        Ok(Self(id.as_str().into()))
     }
 }
 
@@ -177,9 +382,6 @@ impl FromStr for VideoId {
 pub struct ChannelId(pub(crate) String);
 
 #[cfg(feature = "sso")]
-// This is just a newtype around the smartstring type which has serde support, therefore there is
-// no unsafe here.
-#[allow(clippy::unsafe_derive_deserialize)]
 #[derive(Debug, Clone, PartialEq, Eq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
 /// The ID of a channel.
 pub struct ChannelId(pub(crate) smartstring::alias::String);
@@ -218,6 +420,9 @@ impl ChannelId {
 
     /// Get videos that this channel has uploaded.
     ///
+    /// Only the first page of videos is returned, use
+    /// [`videos_all`][`Self::videos_all`] to get all of them.
+    ///
     /// # Examples
     ///
     /// Print some videos uploaded by Kiara.
@@ -254,6 +459,35 @@ impl ChannelId {
         )
     }
 
+    /// Get all videos that this channel has uploaded, fetching every page.
+    ///
+    /// # Examples
+    ///
+    /// Count all videos uploaded by Kiara.
+    /// ```rust
+    /// use holodex::model::id::ChannelId;
+    ///
+    /// # if std::env::var_os("HOLODEX_API_TOKEN").is_none() {
+    /// #   std::env::set_var("HOLODEX_API_TOKEN", "my-api-token");
+    /// # }
+    /// let token = std::env::var("HOLODEX_API_TOKEN").unwrap();
+    /// let client = holodex::Client::new(&token)?;
+    ///
+    /// let channel_id: ChannelId = "UCHsx4Hqa-1ORjQTh9TYDhww".parse()?;
+    /// let videos = channel_id.videos_all(&client)?;
+    ///
+    /// println!("{}", videos.len());
+    /// # Ok::<(), holodex::errors::Error>(())
+    /// ```
+    ///
+    /// # Errors
+    /// Will return [`Error::ApiRequestFailed`] if sending any of the API requests fails.
+    ///
+    /// Will return [`Error::InvalidResponse`] if the API returned a faulty response or server error.
+    pub fn videos_all(&self, client: &Client) -> Result<Vec<Video>, Error> {
+        Self::all_channel_video_type(client, self, ChannelVideoType::Videos)
+    }
+
     #[cfg(feature = "streams")]
     /// Returns a stream of all videos that this channel has uploaded.
     ///
@@ -282,11 +516,14 @@ impl ChannelId {
     /// # })
     /// # }
     pub fn video_stream(self, client: &Client) -> impl Stream<Item = Result<Video, Error>> + '_ {
-        Self::stream_channel_video_type(client, self, ChannelVideoType::Videos)
+        stream_channel_video_type(client, self, ChannelVideoType::Videos)
     }
 
     /// Get clips related to this channel.
     ///
+    /// Only the first page of clips is returned, use
+    /// [`clips_all`][`Self::clips_all`] to get all of them.
+    ///
     /// # Examples
     ///
     /// Show some clips related to Uto.
@@ -323,6 +560,35 @@ impl ChannelId {
         )
     }
 
+    /// Get all clips related to this channel, fetching every page.
+    ///
+    /// # Examples
+    ///
+    /// Count all clips related to Uto.
+    /// ```rust
+    /// use holodex::model::id::ChannelId;
+    ///
+    /// # if std::env::var_os("HOLODEX_API_TOKEN").is_none() {
+    /// #   std::env::set_var("HOLODEX_API_TOKEN", "my-api-token");
+    /// # }
+    /// let token = std::env::var("HOLODEX_API_TOKEN").unwrap();
+    /// let client = holodex::Client::new(&token)?;
+    ///
+    /// let channel_id: ChannelId = "UCdYR5Oyz8Q4g0ZmB4PkTD7g".parse()?;
+    /// let clips = channel_id.clips_all(&client)?;
+    ///
+    /// println!("{}", clips.len());
+    /// # Ok::<(), holodex::errors::Error>(())
+    /// ```
+    ///
+    /// # Errors
+    /// Will return [`Error::ApiRequestFailed`] if sending any of the API requests fails.
+    ///
+    /// Will return [`Error::InvalidResponse`] if the API returned a faulty response or server error.
+    pub fn clips_all(&self, client: &Client) -> Result<Vec<Video>, Error> {
+        Self::all_channel_video_type(client, self, ChannelVideoType::Clips)
+    }
+
     #[cfg(feature = "streams")]
     /// Returns a stream of all videos that this channel has uploaded.
     ///
@@ -351,11 +617,14 @@ impl ChannelId {
     /// # })
     /// # }
     pub fn clip_stream(self, client: &Client) -> impl Stream<Item = Result<Video, Error>> + '_ {
-        Self::stream_channel_video_type(client, self, ChannelVideoType::Clips)
+        stream_channel_video_type(client, self, ChannelVideoType::Clips)
     }
 
     /// Get collabs from other videos that mention this channel.
     ///
+    /// Only the first page of collabs is returned, use
+    /// [`collabs_all`][`Self::collabs_all`] to get all of them.
+    ///
     /// # Examples
     ///
     /// Show some collabs with Korone.
@@ -384,7 +653,7 @@ impl ChannelId {
     pub fn collabs(&self, client: &Client) -> Result<PaginatedResult<Video>, Error> {
         client.videos_from_channel(
             self,
-            ChannelVideoType::Clips,
+            ChannelVideoType::Collabs,
             &ChannelVideoFilter {
                 paginated: false,
                 ..ChannelVideoFilter::default()
@@ -392,6 +661,35 @@ impl ChannelId {
         )
     }
 
+    /// Get all collabs from other videos that mention this channel, fetching every page.
+    ///
+    /// # Examples
+    ///
+    /// Count all collabs with Korone.
+    /// ```rust
+    /// use holodex::model::id::ChannelId;
+    ///
+    /// # if std::env::var_os("HOLODEX_API_TOKEN").is_none() {
+    /// #   std::env::set_var("HOLODEX_API_TOKEN", "my-api-token");
+    /// # }
+    /// let token = std::env::var("HOLODEX_API_TOKEN").unwrap();
+    /// let client = holodex::Client::new(&token)?;
+    ///
+    /// let channel_id: ChannelId = "UChAnqc_AY5_I3Px5dig3X1Q".parse()?;
+    /// let collabs = channel_id.collabs_all(&client)?;
+    ///
+    /// println!("{}", collabs.len());
+    /// # Ok::<(), holodex::errors::Error>(())
+    /// ```
+    ///
+    /// # Errors
+    /// Will return [`Error::ApiRequestFailed`] if sending any of the API requests fails.
+    ///
+    /// Will return [`Error::InvalidResponse`] if the API returned a faulty response or server error.
+    pub fn collabs_all(&self, client: &Client) -> Result<Vec<Video>, Error> {
+        Self::all_channel_video_type(client, self, ChannelVideoType::Collabs)
+    }
+
     #[cfg(feature = "streams")]
     /// Returns a stream of all collabs from other videos that have mentioned this channel.
     ///
@@ -420,42 +718,114 @@ impl ChannelId {
     /// # })
     /// # }
     pub fn collab_stream(self, client: &Client) -> impl Stream<Item = Result<Video, Error>> + '_ {
-        Self::stream_channel_video_type(client, self, ChannelVideoType::Collabs)
+        stream_channel_video_type(client, self, ChannelVideoType::Collabs)
+    }
+
+    #[inline]
+    #[must_use]
+    /// Get a link to the channel on `YouTube`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use holodex::model::id::ChannelId;
+    ///
+    /// let channel_id: ChannelId = "UCoSrY_IQQVpmIRZ9Xf-y93g".parse()?;
+    /// assert_eq!(
+    ///     channel_id.youtube_url(),
+    ///     "https://www.youtube.com/channel/UCoSrY_IQQVpmIRZ9Xf-y93g"
+    /// );
+    /// # Ok::<(), holodex::errors::Error>(())
+    /// ```
+    pub fn youtube_url(&self) -> String {
+        format!("{}{}", YOUTUBE_CHANNEL_URL, self.0)
+    }
+
+    #[inline]
+    #[must_use]
+    /// Get a link to the channel on Holodex.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use holodex::model::id::ChannelId;
+    ///
+    /// let channel_id: ChannelId = "UCoSrY_IQQVpmIRZ9Xf-y93g".parse()?;
+    /// assert_eq!(
+    ///     channel_id.holodex_url(),
+    ///     "https://holodex.net/channel/UCoSrY_IQQVpmIRZ9Xf-y93g"
+    /// );
+    /// # Ok::<(), holodex::errors::Error>(())
+    /// ```
+    pub fn holodex_url(&self) -> String {
+        format!("{}{}", HOLODEX_CHANNEL_URL, self.0)
     }
 
-    #[cfg(feature = "streams")]
     #[allow(clippy::cast_possible_wrap, clippy::cast_possible_truncation)]
-    fn stream_channel_video_type(
+    fn all_channel_video_type(
         client: &Client,
-        channel_id: ChannelId,
+        channel_id: &Self,
         video_type: ChannelVideoType,
-    ) -> impl Stream<Item = Result<Video, Error>> + '_ {
-        async_stream::try_stream! {
-            const CHUNK_SIZE: u32 = 50;
-
-            let mut filter = ChannelVideoFilter {
-                paginated: true,
-                limit: CHUNK_SIZE,
-                ..ChannelVideoFilter::default()
-            };
-            let mut counter = 0_u32;
+    ) -> Result<Vec<Video>, Error> {
+        const CHUNK_SIZE: u32 = 50;
+
+        let mut filter = ChannelVideoFilter {
+            paginated: true,
+            limit: CHUNK_SIZE,
+            ..ChannelVideoFilter::default()
+        };
+        let mut videos = Vec::new();
+
+        loop {
+            let page = client.videos_from_channel(channel_id, video_type, &filter)?;
+            let has_more = page.has_more(videos.len() + page.len());
+            videos.extend(page);
+
+            if !has_more {
+                break;
+            }
 
-            while let PaginatedResult::Page { total, items } =
-                client.videos_from_channel(&channel_id, video_type, &filter)?
-            {
-                counter += items.len() as u32;
-                let total: u32 = total.into();
+            filter.offset += CHUNK_SIZE as i32;
+        }
 
-                for video in items {
-                    yield video;
-                }
+        Ok(videos)
+    }
+}
 
-                if counter >= total {
-                    break;
-                }
+#[cfg(feature = "streams")]
+// Kept outside of `impl ChannelId` so that the unsafe code generated by `async-stream`
+// does not make clippy flag the `Deserialize` derive on `ChannelId`.
+#[allow(clippy::cast_possible_wrap, clippy::cast_possible_truncation)]
+fn stream_channel_video_type(
+    client: &Client,
+    channel_id: ChannelId,
+    video_type: ChannelVideoType,
+) -> impl Stream<Item = Result<Video, Error>> + '_ {
+    async_stream::try_stream! {
+        const CHUNK_SIZE: u32 = 50;
+
+        let mut filter = ChannelVideoFilter {
+            paginated: true,
+            limit: CHUNK_SIZE,
+            ..ChannelVideoFilter::default()
+        };
+        let mut received = 0_usize;
+
+        loop {
+            let page = client.videos_from_channel(&channel_id, video_type, &filter)?;
+            received += page.len();
+            // Some endpoints return every video at once instead of a page, so those are yielded as well.
+            let has_more = page.has_more(received);
+
+            for video in page {
+                yield video;
+            }
 
-                filter.offset += CHUNK_SIZE as i32;
+            if !has_more {
+                break;
             }
+
+            filter.offset += CHUNK_SIZE as i32;
         }
     }
 }
@@ -474,6 +844,71 @@ impl Deref for ChannelId {
     }
 }
 
+/// Compare an ID against a string slice.
+///
+/// # Examples
+///
+/// ```rust
+/// use holodex::model::id::ChannelId;
+///
+/// let id: ChannelId = "UCS9uQI-jC3DE0L4IpXyvr6w".parse()?;
+///
+/// assert_eq!(id, *"UCS9uQI-jC3DE0L4IpXyvr6w");
+/// assert_eq!(id, "UCS9uQI-jC3DE0L4IpXyvr6w");
+/// assert_eq!(id, "UCS9uQI-jC3DE0L4IpXyvr6w".to_owned());
+/// assert_ne!(id, "something else");
+/// # Ok::<(), holodex::errors::Error>(())
+/// ```
+impl PartialEq<str> for ChannelId {
+    fn eq(&self, other: &str) -> bool {
+        &**self == other
+    }
+}
+
+impl PartialEq<&str> for ChannelId {
+    fn eq(&self, other: &&str) -> bool {
+        &**self == *other
+    }
+}
+
+impl PartialEq<String> for ChannelId {
+    fn eq(&self, other: &String) -> bool {
+        &**self == other
+    }
+}
+
+impl AsRef<str> for ChannelId {
+    fn as_ref(&self) -> &str {
+        self
+    }
+}
+
+/// Convert an ID into the raw ID string.
+///
+/// # Examples
+///
+/// ```rust
+/// use holodex::model::id::ChannelId;
+///
+/// fn takes_str(id: impl AsRef<str>) -> usize {
+///     id.as_ref().len()
+/// }
+///
+/// let id: ChannelId = "UCS9uQI-jC3DE0L4IpXyvr6w".parse()?;
+/// assert_eq!(takes_str(&id), "UCS9uQI-jC3DE0L4IpXyvr6w".len());
+///
+/// let raw: String = id.into();
+/// assert_eq!(raw, "UCS9uQI-jC3DE0L4IpXyvr6w");
+/// # Ok::<(), holodex::errors::Error>(())
+/// ```
+impl From<ChannelId> for String {
+    fn from(id: ChannelId) -> Self {
+        // Only a no-op without the `sso` feature.
+        #[allow(clippy::useless_conversion)]
+        id.0.into()
+    }
+}
+
 impl TryFrom<String> for ChannelId {
     type Error = Error;
 
@@ -482,6 +917,27 @@ impl TryFrom<String> for ChannelId {
     }
 }
 
+/// Parse a channel ID, either on its own or from a link to the channel.
+///
+/// Only IDs starting with `UC` are accepted. `YouTube` handles such as `@AmeliaWatson`
+/// and custom URLs cannot be resolved, since the Holodex API has no way of looking them up,
+/// and are rejected with [`Error::UnresolvableChannelHandle`].
+///
+/// # Examples
+///
+/// ```rust
+/// use holodex::{errors::Error, model::id::ChannelId};
+///
+/// let id: ChannelId = "https://www.youtube.com/channel/UCyl1z3jo3XHR1riLFKG5UAg".parse()?;
+/// assert_eq!(id, "UCyl1z3jo3XHR1riLFKG5UAg");
+///
+/// let handle = "@WatsonAmeliaCh".parse::<ChannelId>();
+/// assert!(matches!(handle, Err(Error::UnresolvableChannelHandle(_))));
+///
+/// let custom_url = "https://www.youtube.com/c/WatsonAmeliaCh".parse::<ChannelId>();
+/// assert!(matches!(custom_url, Err(Error::UnresolvableChannelHandle(_))));
+/// # Ok::<(), holodex::errors::Error>(())
+/// ```
 impl FromStr for ChannelId {
     type Err = Error;
 
@@ -490,12 +946,19 @@ impl FromStr for ChannelId {
         #[allow(clippy::expect_used)]
         let regex = Regex::new(r"UC[0-9a-zA-Z_-]{21}[AQgw]").expect("Channel ID regex broke.");
 
-        Ok(Self(
-            regex
-                .find(s)
-                .ok_or_else(|| Error::InvalidChannelId(s.to_owned()))?
-                .as_str()
-                .into(),
-        ))
+        let Some(id) = regex.find(s) else {
+            let is_handle = s.trim().starts_with('@')
+                || ["/@", "/c/", "/user/"]
+                    .iter()
+                    .any(|prefix| s.contains(prefix));
+
+            return Err(if is_handle {
+                Error::UnresolvableChannelHandle(s.to_owned())
+            } else {
+                Error::InvalidChannelId(s.to_owned())
+            });
+        };
+
+        Ok(Self(id.as_str().into()))
     }
 }
@@ -2,10 +2,20 @@ use ::core::fmt;
 use std::str::FromStr;
 use std::{convert::TryFrom, fmt::Display};
 
+use chrono::{DateTime, Duration, SecondsFormat, Utc};
 use serde::de::value::Error;
 use serde::{de::IntoDeserializer as _, Deserialize, Deserializer, Serialize, Serializer};
+use serde_with::{formats::CommaSeparator, DurationSeconds, StringWithSeparator};
 
-use super::{Language, Organisation};
+#[cfg(feature = "extra-fields")]
+use super::ExtraFields;
+use crate::errors::ParseError;
+
+use super::{
+    id::{ChannelId, VideoId},
+    Channel, ChannelMin, Comment, Language, LiveStatus, Organisation, PaginatedTotal, Topic, Video,
+    VideoChannel, VideoFull, VideoLiveInfo, VideoStatus, VideoType,
+};
 
 impl Serialize for Language {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
@@ -66,46 +76,21 @@ impl<'de> Deserialize<'de> for Language {
     where
         D: Deserializer<'de>,
     {
-        #[derive(Deserialize)]
-        #[allow(dead_code)]
-        enum LangDe {
-            #[serde(rename(deserialize = "all"))]
-            All,
-            #[serde(rename(deserialize = "en"))]
-            English,
-            #[serde(rename(deserialize = "es"))]
-            Spanish,
-            #[serde(rename(deserialize = "id"))]
-            Indonesian,
-            #[serde(rename(deserialize = "ja"))]
-            Japanese,
-            #[serde(rename(deserialize = "ko"))]
-            Korean,
-            #[serde(rename(deserialize = "ru"))]
-            Russian,
-            #[serde(rename(deserialize = "zh"))]
-            Chinese,
-        }
+        let tag = String::deserialize(deserializer)?;
 
-        #[derive(Deserialize)]
-        #[serde(untagged)]
-        enum LangDeUntagged {
-            Enum(LangDe),
-            Other(String),
-        }
+        // Region subtags such as the `US` in `en-US` are ignored, so that each language has one variant.
+        let primary = tag.split('-').next().unwrap_or_default();
 
-        let value = match <LangDeUntagged as Deserialize>::deserialize(deserializer)? {
-            LangDeUntagged::Enum(e) => match e {
-                LangDe::All => Language::All,
-                LangDe::English => Language::English,
-                LangDe::Spanish => Language::Spanish,
-                LangDe::Indonesian => Language::Indonesian,
-                LangDe::Japanese => Language::Japanese,
-                LangDe::Korean => Language::Korean,
-                LangDe::Russian => Language::Russian,
-                LangDe::Chinese => Language::Chinese,
-            },
-            LangDeUntagged::Other(v) => Language::Other(v),
+        let value = match primary.to_ascii_lowercase().as_str() {
+            "all" => Language::All,
+            "en" => Language::English,
+            "es" => Language::Spanish,
+            "id" => Language::Indonesian,
+            "ja" => Language::Japanese,
+            "ko" => Language::Korean,
+            "ru" => Language::Russian,
+            "zh" => Language::Chinese,
+            _ => Language::Other(tag),
         };
         Ok(value)
     }
@@ -132,6 +117,49 @@ impl TryFrom<&str> for Language {
     }
 }
 
+macro_rules! impl_try_from_value {
+    ($($(#[$attr:meta])* $ty:ty),+ $(,)?) => {
+        $(
+            $(#[$attr])*
+            impl TryFrom<serde_json::Value> for $ty {
+                type Error = ParseError;
+
+                fn try_from(value: serde_json::Value) -> Result<Self, Self::Error> {
+                    serde_json::from_value(value).map_err(ParseError::ValueParseError)
+                }
+            }
+        )+
+    };
+}
+
+impl_try_from_value!(
+    /// Parse JSON that was received through other means than the client, such as a webhook.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use holodex::model::Video;
+    ///
+    /// let value = serde_json::json!({
+    ///     "id": "IhiievWaZMI", "title": "Graduation", "type": "stream", "status": "past",
+    ///     "available_at": "2021-07-01T12:00:00Z", "channel_id": "UCS9uQI-jC3DE0L4IpXyvr6w"
+    /// });
+    ///
+    /// let video = Video::try_from(value)?;
+    /// assert_eq!(video.title, "Graduation");
+    ///
+    /// assert!(Video::try_from(serde_json::json!({ "id": "IhiievWaZMI" })).is_err());
+    /// # Ok::<(), holodex::errors::ParseError>(())
+    /// ```
+    Video,
+    VideoFull,
+    Channel,
+    ChannelMin,
+    Comment,
+    LiveStatus,
+    Topic,
+);
+
 impl<'de> Deserialize<'de> for Organisation {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -220,3 +248,154 @@ impl Display for Organisation {
         }
     }
 }
+
+/// Deserialization target for [`Video`], which allows `available_at` to be missing.
+///
+/// Mirrors the fields of [`Video`], and keeps its serde attributes, which live here instead.
+#[derive(Deserialize)]
+pub(super) struct VideoDe {
+    id: VideoId,
+    title: String,
+    #[serde(rename = "type")]
+    video_type: VideoType,
+    #[serde(default)]
+    #[serde(rename = "topic_id")]
+    topic: Option<String>,
+    #[serde(default)]
+    published_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    available_at: Option<DateTime<Utc>>,
+    #[serde(with = "serde_with::As::<Option<DurationSeconds<i64>>>")]
+    #[serde(default)]
+    duration: Option<Duration>,
+    status: VideoStatus,
+    #[serde(flatten)]
+    live_info: VideoLiveInfo,
+    #[serde(default)]
+    description: Option<String>,
+    #[serde(rename = "songcount")]
+    #[serde(default)]
+    song_count: Option<u32>,
+    #[serde(alias = "channel_id")]
+    channel: VideoChannel,
+    #[serde(default)]
+    mentions: Vec<ChannelMin>,
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    extra: ExtraFields,
+}
+
+impl TryFrom<VideoDe> for Video {
+    type Error = String;
+
+    fn try_from(video: VideoDe) -> Result<Self, Self::Error> {
+        // Both structs are listed exhaustively, so a field added to only one of them fails to compile.
+        let VideoDe {
+            id,
+            title,
+            video_type,
+            topic,
+            published_at,
+            available_at,
+            duration,
+            status,
+            live_info,
+            description,
+            song_count,
+            channel,
+            mentions,
+            #[cfg(feature = "extra-fields")]
+            extra,
+        } = video;
+
+        let available_at = available_at
+            .or(live_info.end_actual)
+            .or(live_info.start_actual)
+            .or(live_info.start_scheduled)
+            .or(published_at)
+            .ok_or_else(|| {
+                format!("video {id} has no `available_at` and no timestamp to derive it from")
+            })?;
+
+        Ok(Self {
+            id,
+            title,
+            video_type,
+            topic,
+            published_at,
+            available_at,
+            duration,
+            status,
+            live_info,
+            description,
+            song_count,
+            channel,
+            mentions,
+            #[cfg(feature = "extra-fields")]
+            extra,
+        })
+    }
+}
+
+/// Deserialize an optional count that the API may send either as a number or as a string.
+pub(super) fn number_or_string<'de, D>(deserializer: D) -> Result<Option<u32>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(Option::<PaginatedTotal>::deserialize(deserializer)?.map(Into::into))
+}
+
+/// Serialize an optional timestamp as RFC 3339 in UTC, without fractional seconds.
+#[allow(clippy::ref_option)]
+pub(super) fn rfc3339_seconds<S>(
+    date: &Option<DateTime<Utc>>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    match date {
+        Some(date) => serializer.serialize_some(&date.to_rfc3339_opts(SecondsFormat::Secs, true)),
+        None => serializer.serialize_none(),
+    }
+}
+
+/// Serialize video statuses as a comma-separated list, failing on [`VideoStatus::Unknown`].
+///
+/// The list is built from the [`Display`] output, which would otherwise send `unknown` to the API.
+#[allow(clippy::ptr_arg)]
+pub(super) fn known_statuses<S>(
+    statuses: &Vec<VideoStatus>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    if statuses.contains(&VideoStatus::Unknown) {
+        return Err(serde::ser::Error::custom(
+            "unknown video statuses cannot be used in filters",
+        ));
+    }
+
+    serde_with::As::<StringWithSeparator<CommaSeparator, VideoStatus>>::serialize(
+        statuses, serializer,
+    )
+}
+
+/// Deserialize a channel ID given either as a string or as an object with an `id` field,
+/// skipping any other channel metadata.
+pub(super) fn channel_id_or_min<'de, D>(deserializer: D) -> Result<ChannelId, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum ChannelRef {
+        Id(ChannelId),
+        Min { id: ChannelId },
+    }
+
+    Ok(match ChannelRef::deserialize(deserializer)? {
+        ChannelRef::Id(id) | ChannelRef::Min { id } => id,
+    })
+}
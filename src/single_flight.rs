@@ -0,0 +1,113 @@
+//! Sharing the response of identical requests that are in flight at the same time.
+
+use std::{
+    collections::{hash_map::Entry, HashMap},
+    sync::{Arc, Condvar, Mutex, PoisonError},
+};
+
+/// The body of a successful response, shared between every caller waiting for it.
+pub type SharedBody = Arc<Vec<u8>>;
+
+#[derive(Debug, Default)]
+/// Tracks which requests are in flight, so that identical requests can wait for the first one.
+pub struct SingleFlight {
+    in_flight: Mutex<HashMap<String, Arc<Flight>>>,
+}
+
+#[derive(Debug, Default)]
+struct Flight {
+    outcome: Mutex<Outcome>,
+    done: Condvar,
+}
+
+#[derive(Debug, Default)]
+enum Outcome {
+    #[default]
+    InFlight,
+    Succeeded(SharedBody),
+    Failed,
+}
+
+impl SingleFlight {
+    /// Run `fetch` for the request identified by `key`, unless an identical request is already in flight,
+    /// in which case its response is shared instead.
+    ///
+    /// Errors are not shared, if the request in flight fails, `fetch` is run to get this caller's own result.
+    pub fn run<E>(
+        &self,
+        key: String,
+        fetch: impl FnOnce() -> Result<Vec<u8>, E>,
+    ) -> Result<SharedBody, E> {
+        let mut in_flight = self
+            .in_flight
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        let (flight, is_leader) = match in_flight.entry(key.clone()) {
+            Entry::Occupied(entry) => (Arc::clone(entry.get()), false),
+            Entry::Vacant(entry) => (Arc::clone(entry.insert(Arc::default())), true),
+        };
+        drop(in_flight);
+
+        if !is_leader {
+            if let Some(body) = flight.wait() {
+                return Ok(body);
+            }
+
+            return fetch().map(Arc::new);
+        }
+
+        // Complete the flight even if `fetch` fails or panics, so that no caller waits forever.
+        let mut landing = Landing {
+            single_flight: self,
+            key,
+            flight,
+            body: None,
+        };
+
+        let body = Arc::new(fetch()?);
+        landing.body = Some(Arc::clone(&body));
+
+        Ok(body)
+    }
+}
+
+impl Flight {
+    fn wait(&self) -> Option<SharedBody> {
+        let outcome = self.outcome.lock().unwrap_or_else(PoisonError::into_inner);
+        let outcome = self
+            .done
+            .wait_while(outcome, |outcome| matches!(outcome, Outcome::InFlight))
+            .unwrap_or_else(PoisonError::into_inner);
+
+        match &*outcome {
+            Outcome::Succeeded(body) => Some(Arc::clone(body)),
+            Outcome::InFlight | Outcome::Failed => None,
+        }
+    }
+}
+
+/// Completes a flight when dropped, waking up every caller waiting for it.
+struct Landing<'a> {
+    single_flight: &'a SingleFlight,
+    key: String,
+    flight: Arc<Flight>,
+    body: Option<SharedBody>,
+}
+
+impl Drop for Landing<'_> {
+    fn drop(&mut self) {
+        self.single_flight
+            .in_flight
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .remove(&self.key);
+
+        *self
+            .flight
+            .outcome
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner) =
+            self.body.take().map_or(Outcome::Failed, Outcome::Succeeded);
+        self.flight.done.notify_all();
+    }
+}
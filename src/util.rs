@@ -1,6 +1,9 @@
-use std::io::Read;
+use std::{
+    io::{BufReader, Read},
+    marker::PhantomData,
+};
 
-use serde::Deserialize;
+use serde::{de::DeserializeOwned, Deserialize};
 
 use crate::errors::{ParseError, ServerError, ValidationError};
 
@@ -8,36 +11,335 @@ pub fn is_default<T: Default + PartialEq>(t: &T) -> bool {
     t == &T::default()
 }
 
+/// The largest response body that will be read, in bytes.
+pub const MAX_RESPONSE_SIZE: usize = 32 * 1024 * 1024;
+
 fn into_bytes(response: ureq::Response) -> Result<Vec<u8>, ParseError> {
+    // Content-Length is only a hint, so never trust it for more than the maximum size.
     let len = response
         .header("Content-Length")
         .and_then(|s| s.parse::<usize>().ok())
-        .unwrap_or(0);
+        .unwrap_or(0)
+        .min(MAX_RESPONSE_SIZE);
 
     let mut bytes: Vec<u8> = Vec::with_capacity(len);
 
-    match response.into_reader().read_to_end(&mut bytes) {
+    // Read one byte past the limit to be able to tell a body of exactly the maximum size
+    // apart from one that is too large.
+    let limit = MAX_RESPONSE_SIZE as u64 + 1;
+
+    match response.into_reader().take(limit).read_to_end(&mut bytes) {
+        Ok(_) if bytes.len() > MAX_RESPONSE_SIZE => {
+            Err(ParseError::ResponseTooLarge(MAX_RESPONSE_SIZE))
+        }
         Ok(_) => Ok(bytes),
         Err(e) => Err(ParseError::ResponseDecodeError(e)),
     }
 }
 
-pub fn validate_response<T>(response: ureq::Response) -> Result<T, ValidationError>
+/// Read the body of a successful response, or the error returned by the API.
+///
+/// `T` is the type the response is expected to have, used to parse any error message.
+pub fn response_bytes<T>(response: ureq::Response) -> Result<Vec<u8>, ValidationError>
 where
     T: for<'de> Deserialize<'de> + std::fmt::Debug,
 {
     if let status @ (400..=599) = response.status() {
-        let bytes = into_bytes(response).map_err(|e| {
-            ValidationError::ServerError(ServerError::ErrorCodeWithValueParseError(status, e))
-        })?;
-
-        Err(match validate_json_bytes::<T>(&bytes) {
-            Ok(val) => ServerError::ErrorCodeWithValue(status, format!("{val:?}")).into(),
-            Err(error) => ServerError::ErrorCodeWithValueParseError(status, error).into(),
-        })
+        Err(error_response::<T>(status, response))
     } else {
-        let bytes = into_bytes(response).map_err(ValidationError::ParseError)?;
-        validate_json_bytes(&bytes).map_err(std::convert::Into::into)
+        into_bytes(response).map_err(ValidationError::ParseError)
+    }
+}
+
+/// Read the error returned by the API in a response with an error status.
+fn error_response<T>(status: u16, response: ureq::Response) -> ValidationError
+where
+    T: for<'de> Deserialize<'de> + std::fmt::Debug,
+{
+    let bytes = match into_bytes(response) {
+        Ok(bytes) => bytes,
+        Err(e) => return ServerError::ErrorCodeWithValueParseError(status, e).into(),
+    };
+
+    match validate_json_bytes::<T>(&bytes) {
+        Ok(val) => ServerError::ErrorCodeWithValue(status, format!("{val:?}")).into(),
+        Err(error) => ServerError::ErrorCodeWithValueParseError(status, error).into(),
+    }
+}
+
+/// Start reading the items of a successful paginated response one at a time, or return the error returned by the API.
+///
+/// Unlike [`response_bytes`], the body is never buffered as a whole, so it is not limited to [`MAX_RESPONSE_SIZE`].
+/// Both shapes of [`PaginatedResult`][`crate::model::PaginatedResult`] are supported.
+///
+/// `T` is the type of each item, which must be a JSON object.
+pub fn validate_response_streaming<T>(
+    response: ureq::Response,
+) -> Result<StreamingItems<T>, ValidationError>
+where
+    T: DeserializeOwned + std::fmt::Debug,
+{
+    if let status @ (400..=599) = response.status() {
+        return Err(error_response::<T>(status, response));
+    }
+
+    Ok(StreamingItems {
+        reader: Lookahead::new(BufReader::new(response.into_reader())),
+        state: StreamingState::Start,
+        item: PhantomData,
+    })
+}
+
+/// An iterator over the items of a response, parsing each item as it is read.
+///
+/// Created by [`validate_response_streaming`]. Once an error is returned, the iterator ends,
+/// since the position in the response is no longer known.
+pub struct StreamingItems<T> {
+    reader: Lookahead<BufReader<Box<dyn Read + Send + Sync + 'static>>>,
+    state: StreamingState,
+    item: PhantomData<fn() -> T>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum StreamingState {
+    /// The items array has not been found yet.
+    Start,
+    /// The items array was just opened.
+    First,
+    /// At least one item has been read.
+    Rest,
+    /// The items array was closed, or an error occurred.
+    Done,
+}
+
+impl<T> StreamingItems<T>
+where
+    T: DeserializeOwned,
+{
+    fn next_item(&mut self) -> Result<Option<T>, ParseError> {
+        if self.state == StreamingState::Start {
+            self.find_items()?;
+            self.state = StreamingState::First;
+        }
+
+        match (self.state, self.reader.peek_non_whitespace()?) {
+            (_, Some(b']')) => return Ok(None),
+            (StreamingState::Rest, Some(b',')) => self.reader.consume(),
+            (StreamingState::Rest, found) => return Err(unexpected("`,` or `]`", found)),
+            _ => {}
+        }
+
+        let mut deserializer = serde_json::Deserializer::from_reader(&mut self.reader);
+        let item = T::deserialize(&mut deserializer).map_err(ParseError::ValueParseError)?;
+        self.state = StreamingState::Rest;
+
+        Ok(Some(item))
+    }
+
+    /// Advance the reader past the opening bracket of the items array.
+    fn find_items(&mut self) -> Result<(), ParseError> {
+        match self.reader.peek_non_whitespace()? {
+            Some(b'[') => {
+                self.reader.consume();
+                return Ok(());
+            }
+            Some(b'{') => self.reader.consume(),
+            found => return Err(unexpected("`[` or `{`", found)),
+        }
+
+        loop {
+            if self.reader.peek_non_whitespace()? != Some(b'"') {
+                return Err(unexpected("an `items` field", self.reader.peek()?));
+            }
+
+            let mut deserializer = serde_json::Deserializer::from_reader(&mut self.reader);
+            let key =
+                String::deserialize(&mut deserializer).map_err(ParseError::ValueParseError)?;
+            self.reader.expect(b':')?;
+
+            if key == "items" {
+                return self.reader.expect(b'[');
+            }
+
+            self.reader.skip_value()?;
+
+            match self.reader.peek_non_whitespace()? {
+                Some(b',') => self.reader.consume(),
+                found => return Err(unexpected("an `items` field", found)),
+            }
+        }
+    }
+}
+
+impl<T> Iterator for StreamingItems<T>
+where
+    T: DeserializeOwned,
+{
+    type Item = Result<T, ParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.state == StreamingState::Done {
+            return None;
+        }
+
+        let item = self.next_item();
+
+        if !matches!(item, Ok(Some(_))) {
+            self.state = StreamingState::Done;
+        }
+
+        item.transpose()
+    }
+}
+
+impl<T> std::fmt::Debug for StreamingItems<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("StreamingItems")
+            .field("state", &self.state)
+            .finish_non_exhaustive()
+    }
+}
+
+fn unexpected(expected: &str, found: Option<u8>) -> ParseError {
+    let found = found.map_or_else(
+        || "end of response".to_owned(),
+        |byte| format!("`{}`", char::from(byte)),
+    );
+
+    ParseError::ValueParseError(serde::de::Error::custom(format!(
+        "expected {expected}, found {found}"
+    )))
+}
+
+/// A reader that can look at the next byte without consuming it.
+///
+/// Values parsed with `serde_json` from this reader must be self-delimiting, such as objects or strings,
+/// since `serde_json` discards any byte it looked at past the end of the value.
+struct Lookahead<R> {
+    inner: R,
+    peeked: Option<u8>,
+}
+
+impl<R: Read> Lookahead<R> {
+    const fn new(inner: R) -> Self {
+        Self {
+            inner,
+            peeked: None,
+        }
+    }
+
+    fn peek(&mut self) -> Result<Option<u8>, ParseError> {
+        if self.peeked.is_none() {
+            let mut byte = [0];
+
+            self.peeked = match self.inner.read_exact(&mut byte) {
+                Ok(()) => Some(byte[0]),
+                Err(e) if e.kind() == std::io::ErrorKind::UnexpectedEof => None,
+                Err(e) => return Err(ParseError::ResponseDecodeError(e)),
+            };
+        }
+
+        Ok(self.peeked)
+    }
+
+    const fn consume(&mut self) {
+        self.peeked = None;
+    }
+
+    fn next_byte(&mut self) -> Result<Option<u8>, ParseError> {
+        let byte = self.peek()?;
+        self.consume();
+
+        Ok(byte)
+    }
+
+    fn peek_non_whitespace(&mut self) -> Result<Option<u8>, ParseError> {
+        while let Some(byte) = self.peek()? {
+            if !byte.is_ascii_whitespace() {
+                return Ok(Some(byte));
+            }
+
+            self.consume();
+        }
+
+        Ok(None)
+    }
+
+    fn expect(&mut self, expected: u8) -> Result<(), ParseError> {
+        match self.peek_non_whitespace()? {
+            Some(byte) if byte == expected => {
+                self.consume();
+                Ok(())
+            }
+            found => Err(unexpected(&format!("`{}`", char::from(expected)), found)),
+        }
+    }
+
+    /// Skip over a JSON value without parsing it.
+    fn skip_value(&mut self) -> Result<(), ParseError> {
+        match self.peek_non_whitespace()? {
+            Some(b'"') => {
+                self.consume();
+                self.skip_string()
+            }
+            Some(b'{' | b'[') => {
+                self.consume();
+                let mut depth = 1_usize;
+
+                while depth > 0 {
+                    match self.next_byte()? {
+                        Some(b'"') => self.skip_string()?,
+                        Some(b'{' | b'[') => depth += 1,
+                        Some(b'}' | b']') => depth -= 1,
+                        Some(_) => {}
+                        None => return Err(unexpected("the end of the value", None)),
+                    }
+                }
+
+                Ok(())
+            }
+            Some(_) => {
+                while let Some(byte) = self.peek()? {
+                    if matches!(byte, b',' | b'}' | b']') || byte.is_ascii_whitespace() {
+                        break;
+                    }
+
+                    self.consume();
+                }
+
+                Ok(())
+            }
+            None => Err(unexpected("a value", None)),
+        }
+    }
+
+    /// Skip the rest of a string whose opening quote has been consumed.
+    fn skip_string(&mut self) -> Result<(), ParseError> {
+        loop {
+            match self.next_byte()? {
+                Some(b'"') => return Ok(()),
+                Some(b'\\') => {
+                    self.next_byte()?;
+                }
+                Some(_) => {}
+                None => return Err(unexpected("the end of the string", None)),
+            }
+        }
+    }
+}
+
+impl<R: Read> Read for Lookahead<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        match (self.peeked.take(), buf.first_mut()) {
+            (Some(byte), Some(first)) => {
+                *first = byte;
+                Ok(1)
+            }
+            (peeked, _) => {
+                self.peeked = peeked;
+                self.inner.read(buf)
+            }
+        }
     }
 }
 
@@ -50,11 +352,63 @@ where
     match data {
         Ok(data) => Ok(data),
         Err(e) => Err(match serde_json::from_slice::<serde_json::Value>(bytes) {
-            Ok(v) => ParseError::ResponseParseError(e, v),
+            Ok(_) => {
+                let excerpt = excerpt(bytes, &e);
+                ParseError::ResponseParseError(e, excerpt)
+            }
             Err(e) => match std::str::from_utf8(bytes) {
-                Ok(s) => ParseError::ResponseJsonParseError(e, s.to_owned()),
+                Ok(_) => {
+                    let excerpt = excerpt(bytes, &e);
+                    ParseError::ResponseJsonParseError(e, excerpt)
+                }
                 Err(e) => ParseError::ResponseUtf8Error(e),
             },
         }),
     }
 }
+
+/// The maximum length in bytes of a response excerpt included in a [`ParseError`].
+pub const MAX_EXCERPT_LEN: usize = 2048;
+
+/// Extract at most [`MAX_EXCERPT_LEN`] bytes of the response, centered around where parsing failed.
+fn excerpt(bytes: &[u8], error: &serde_json::Error) -> String {
+    if bytes.len() <= MAX_EXCERPT_LEN {
+        return String::from_utf8_lossy(bytes).into_owned();
+    }
+
+    // Lines and columns reported by serde_json are 1-indexed, with columns counted in bytes.
+    let line_start = bytes
+        .split_inclusive(|&b| b == b'\n')
+        .take(error.line().saturating_sub(1))
+        .map(<[u8]>::len)
+        .sum::<usize>();
+    let position = (line_start + error.column().saturating_sub(1)).min(bytes.len());
+
+    let start = position
+        .saturating_sub(MAX_EXCERPT_LEN / 2)
+        .min(bytes.len() - MAX_EXCERPT_LEN);
+    let end = start + MAX_EXCERPT_LEN;
+
+    format!(
+        "{}{}{}",
+        if start > 0 { "..." } else { "" },
+        String::from_utf8_lossy(bytes.get(start..end).unwrap_or_default()),
+        if end < bytes.len() { "..." } else { "" },
+    )
+}
+
+/// Serialize a request body for an error message, truncated to at most [`MAX_EXCERPT_LEN`] bytes.
+pub fn body_excerpt(body: &serde_json::Value) -> String {
+    let body = body.to_string();
+
+    if body.len() <= MAX_EXCERPT_LEN {
+        return body;
+    }
+
+    let end = (0..=MAX_EXCERPT_LEN)
+        .rev()
+        .find(|&i| body.is_char_boundary(i))
+        .unwrap_or_default();
+
+    format!("{}...", body.get(..end).unwrap_or_default())
+}